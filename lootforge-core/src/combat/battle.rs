@@ -0,0 +1,180 @@
+use enumset::EnumSet;
+
+use crate::{combat::enemy::{EnemyAffinity, EnemyKind}, dungeon::{dungeon_data::DungeonTick, modifier::DungeonModifier}, equipment::wardrobe::EquipmentSet, prelude::*};
+use super::{combatant::Combatant, event::{CombatEvent, EventSink}, rng::CombatRng};
+
+#[derive(Debug)]
+pub struct Battle {
+    pub tick: u64,
+    pub fighter: Combatant,
+    // ranger and mage only join the party if something is equipped
+    pub ranger: Option<Combatant>,
+    pub mage: Option<Combatant>,
+    pub enemies: Vec<Combatant>,
+    // everything that happened in this battle so far, with the tick it happened on
+    pub log: Vec<(u64, CombatEvent)>,
+    events: EventSink,
+    rng: CombatRng,
+}
+
+// the copy gets its own events and rng, otherwise it would roll the dice of the original
+impl Clone for Battle {
+    fn clone(&self) -> Self {
+        let mut battle = Self {
+            tick: self.tick,
+            fighter: self.fighter.clone(),
+            ranger: self.ranger.clone(),
+            mage: self.mage.clone(),
+            enemies: self.enemies.clone(),
+            log: self.log.clone(),
+            events: self.events.detached(),
+            rng: self.rng.detached(),
+        };
+        battle.share();
+        battle
+    }
+}
+
+#[apply(UnitEnum)]
+pub enum BattleResult {
+    Ongoing,
+    Won,
+    Lost,
+}
+
+impl Battle {
+    pub fn new(equip: &EquipmentSet, enemies: Vec<(EnemyKind, EnemyAffinity)>, modifiers: EnumSet<DungeonModifier>, rng: &mut impl Rng) -> Self {
+        let mut battle = Self {
+            tick: 0,
+            fighter: Combatant::fighter(&equip.fighter_equip),
+            ranger: (!equip.ranger_equip.is_empty()).then(|| Combatant::ranger(&equip.ranger_equip)),
+            mage: (!equip.mage_equip.is_empty()).then(|| Combatant::mage(&equip.mage_equip)),
+            enemies: Self::enemies(enemies, 1, modifiers, rng),
+            log: Vec::new(),
+            events: EventSink::default(),
+            rng: CombatRng::new(rng.random()),
+        };
+        battle.share();
+        battle
+    }
+
+    pub fn next(&mut self, enemies: Vec<(EnemyKind, EnemyAffinity)>, depth: u16, modifiers: EnumSet<DungeonModifier>, rng: &mut impl Rng) {
+        self.tick = 0;
+        self.log.clear();
+        self.party_mut().for_each(|c| c.transfer());
+        self.enemies = Self::enemies(enemies, depth, modifiers, rng);
+        self.rng = CombatRng::new(rng.random());
+        self.share();
+    }
+
+    // everyone reports into the same sink, so the log keeps the order things happened in
+    // and rolls from the same rng, so the battle plays out the same when it is replayed
+    fn share(&mut self) {
+        let (events, rng) = (self.events.clone(), self.rng.clone());
+        self.party_mut().for_each(|c| (c.events, c.rng) = (events.clone(), rng.clone()));
+        self.enemies.iter_mut().for_each(|e| (e.events, e.rng) = (events.clone(), rng.clone()));
+    }
+
+    fn enemies(enemies: Vec<(EnemyKind, EnemyAffinity)>, depth: u16, modifiers: EnumSet<DungeonModifier>, rng: &mut impl Rng) -> Vec<Combatant> {
+        enemies.into_iter()
+            .enumerate()
+            .map(|(i, (kind, affinity))| Combatant::enemy(kind, affinity, i as u8, depth, modifiers, rng))
+            .collect()
+    }
+
+    pub fn party(&self) -> impl Iterator<Item = &Combatant> {
+        once(&self.fighter).chain(self.ranger.as_ref()).chain(self.mage.as_ref())
+    }
+    fn party_mut(&mut self) -> impl Iterator<Item = &mut Combatant> {
+        once(&mut self.fighter).chain(self.ranger.as_mut()).chain(self.mage.as_mut())
+    }
+
+    // share is the part of their recovery the party gets, the dead stay dead
+    pub fn recover(&mut self, share: f32) {
+        self.party_mut()
+            .filter(|c| c.alive())
+            .for_each(|c| c.recover(c.stats().recovery * share));
+    }
+    // share of its max health the party has left, the dead count as empty
+    pub fn party_health(&self) -> f32 {
+        let (health, max) = self.party().fold((0., 0.), |(health, max), c| (health + c.health, max + c.stats().max_health));
+        health / max
+    }
+    pub fn mend_wounds(&mut self) {
+        self.party_mut().for_each(|c| c.mend_wounds());
+    }
+
+    pub fn start(&mut self) {
+        self.party_mut().for_each(|c| c.combat_start());
+        self.enemies.iter_mut().for_each(|e| e.combat_start());
+    }
+
+    pub fn tick(&mut self) -> DungeonTick {
+        self.tick += 1;
+        let mut tick_info = DungeonTick::default();
+        let alive_before: Vec<_> = self.party().chain(self.enemies.iter()).map(|c| c.alive()).collect();
+        let Self { fighter, ranger, mage, enemies, .. } = self;
+
+        if fighter.alive() {
+            let allies = alive(ranger.iter_mut().chain(mage.iter_mut()));
+            tick_explorer(fighter, allies, enemies, &mut tick_info);
+        }
+        if let Some(ranger) = ranger.as_mut().filter(|r| r.alive()) {
+            let allies = alive(once(&mut *fighter).chain(mage.iter_mut()));
+            tick_explorer(ranger, allies, enemies, &mut tick_info);
+        }
+        if let Some(mage) = mage.as_mut().filter(|m| m.alive()) {
+            let allies = alive(once(&mut *fighter).chain(ranger.iter_mut()));
+            tick_explorer(mage, allies, enemies, &mut tick_info);
+        }
+
+        for enemy in enemies {
+            if enemy.alive() {
+                let mut party = alive(once(&mut *fighter).chain(ranger.iter_mut()).chain(mage.iter_mut()));
+                if party.is_empty() {
+                    break;
+                }
+                enemy.threat.sort(&mut party);
+                let skill_stats = enemy.tick(
+                    vec![],
+                    party,
+                );
+                tick_info.skills.extend(skill_stats);
+            }
+        }
+
+        let died: Vec<_> = self.party().chain(self.enemies.iter())
+            .zip(alive_before)
+            .filter(|(c, was_alive)| *was_alive && !c.alive())
+            .map(|(c, _)| c.kind)
+            .collect();
+        died.into_iter().for_each(|target| self.events.push(CombatEvent::Died { target }));
+
+        tick_info.events = self.events.take();
+        self.log.extend(tick_info.events.iter().map(|e| (self.tick, *e)));
+        tick_info
+    }
+
+    pub fn result(&self) -> BattleResult {
+        if self.party().all(|c| !c.alive()) {
+            BattleResult::Lost
+        } else if self.enemies.iter().all(|e| !e.alive()) {
+            BattleResult::Won
+        } else {
+            BattleResult::Ongoing
+        }
+    }
+}
+
+fn tick_explorer(explorer: &mut Combatant, allies: Vec<&mut Combatant>, enemies: &mut [Combatant], tick_info: &mut DungeonTick) {
+    let enemies = alive(enemies.iter_mut());
+    // an earlier explorer may have killed the last enemy this tick
+    if enemies.is_empty() {
+        return;
+    }
+    tick_info.skills.extend(explorer.tick(allies, enemies));
+}
+
+fn alive<'a>(combatants: impl Iterator<Item = &'a mut Combatant>) -> Vec<&'a mut Combatant> {
+    combatants.filter(|c| c.alive()).collect()
+}
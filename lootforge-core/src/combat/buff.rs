@@ -0,0 +1,741 @@
+use enum_dispatch::enum_dispatch;
+
+use crate::{
+    combat::skill::{
+        hit::{self, Hit},
+        skill::{Skill, SkillSource, SkillStats},
+    },
+    elemental::{Element, Elemental},
+    prelude::*,
+};
+
+use super::{
+    combatant::{CharStats, Combatant, CombatantKind},
+    event::CombatEvent,
+    skill::{
+        defend::Defend,
+        hit::{PostHit, PreHit, ResponsePostHit, ResponsePreHit},
+    },
+};
+
+// Everything a buff does is declared in its BuffEffect impl, Buffs only knows the rules in general.
+// To add a new buff: write a struct, implement BuffEffect for it, add it to this enum and give it an icon.
+#[apply(Enum)]
+#[enum_dispatch(BuffEffect)]
+pub enum Buff {
+    Attuned(AttunedBuff),
+    Reverb(ReverbBuff),
+    Empowered(EmpoweredBuff),
+    Haste(HasteBuff),
+
+    Bleed(BleedDebuff),
+    Vulnerable(VulnerableDebuff),
+    Confused(ConfusedDebuff),
+    Echo(EchoDebuff),
+
+    Lifelink(LifelinkDebuff),
+    Incapacitated(IncapacitatedDebuff),
+    Dazed(DazedDebuff),
+    Soullink(SoullinkDebuff),
+    Slow(SlowDebuff),
+}
+pub type Debuff = Buff;
+
+// what happens when a buff is added while the owner already has one of the same kind
+#[apply(UnitEnum)]
+pub enum Stacking {
+    // all of them are active at the same time
+    Stack,
+    // only the oldest one is active, the others wait until it runs out or gets consumed
+    Queue,
+    // the new one replaces the old one, which also resets the duration
+    Refresh,
+}
+
+// when an active buff gets used up, independent of its duration
+#[apply(UnitEnum)]
+pub enum Consumption {
+    Never,
+    // the owner finished an attack
+    Attacking,
+    // an attack against the owner comes in, even if it gets blocked
+    Attacked,
+    // an attack against the owner lands
+    Hit,
+}
+
+#[enum_dispatch]
+pub trait BuffEffect {
+    fn icon(&self) -> BuffIcon;
+    // ticks is how long the buff has left, if it has a duration
+    fn describe(&self, ticks: Option<u8>) -> String;
+
+    // in ticks, without one the buff lasts until it is consumed or the battle ends
+    fn duration(&self) -> Option<u8> { None }
+    fn stacking(&self) -> Stacking { Stacking::Stack }
+    fn consumption(&self) -> Consumption { Consumption::Never }
+
+    // damage dealt to the owner every tick, before resistances
+    fn dot(&self, _owner: &Combatant) -> Option<Elemental<f32>> { None }
+
+    fn apply_to_char(&self, _char: &mut CharStats) {}
+    fn apply_pre_hit(&self, _attack: &mut PreHit, _skill: &Skill, _user: &Combatant, _target: &Combatant) {}
+    fn apply_post_hit(&self, _attack: &mut PostHit, _skill: &Skill, _user: &Combatant, _target: &Combatant, _hit: &Hit) {}
+    fn apply_pre_getting_hit(&self, _attack: &mut PreHit) {}
+    fn apply_post_getting_hit(&self, _attack: &mut PostHit) {}
+    fn apply_pre_atk(&self, _resp: &mut ResponsePreHit, _skill: &Skill, _user: &Combatant, _attacker: &Combatant) {}
+    fn apply_post_atk(&self, _resp: &mut ResponsePostHit, _skill: &Skill, _user: &Combatant, _attacker: &Combatant, _hit: &Hit) {}
+    fn apply_to_def(&self, _def: &mut Defend, _skill: &Skill, _user: &Combatant) {}
+}
+
+// who inflicted a debuff, so the damage it deals over time can be credited to the skill and mod behind it
+#[derive(Debug, Clone)]
+pub struct BuffOrigin {
+    pub applier: CombatantKind,
+    pub source: SkillSource,
+    pub mod_id: Option<u16>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DotStats {
+    pub applier: CombatantKind,
+    pub target: CombatantKind,
+    pub debuff: BuffIcon,
+    // after resistances
+    pub damage: Elemental<f32>,
+    pub mod_id: Option<u16>,
+}
+
+#[derive(Debug, Clone)]
+struct ActiveBuff {
+    buff: Buff,
+    ticks: Option<u8>,
+    origin: Option<BuffOrigin>,
+}
+impl ActiveBuff {
+    fn same_kind(&self, other: &Buff) -> bool {
+        std::mem::discriminant(&self.buff) == std::mem::discriminant(other)
+    }
+}
+
+#[derive(Debug, Clone, SmartDefault)]
+pub struct Buffs {
+    // in the order they were added
+    buffs: Vec<ActiveBuff>,
+
+    // lets Combatant know its cached CharStats are outdated, fresh buffs start out changed
+    #[default(Cell::new(true))]
+    changed: Cell<bool>,
+}
+
+impl Buffs {
+    pub fn add(&mut self, buff: Buff, origin: Option<BuffOrigin>) {
+        let stacking = buff.stacking();
+        self.add_stacking(buff, origin, stacking);
+    }
+    // no buff refreshes yet, taking the stacking separately lets the tests check the rule anyway
+    fn add_stacking(&mut self, buff: Buff, origin: Option<BuffOrigin>, stacking: Stacking) {
+        // most buffs don't touch CharStats, but adds are rare compared to stat lookups
+        self.changed.set(true);
+        let ticks = buff.duration();
+        if stacking == Stacking::Refresh {
+            if let Some(old) = self.buffs.iter_mut().find(|b| b.same_kind(&buff)) {
+                *old = ActiveBuff { buff, ticks, origin };
+                return;
+            }
+        }
+        self.buffs.push(ActiveBuff { buff, ticks, origin });
+    }
+
+    fn is_active(&self, i: usize) -> bool {
+        let buff = &self.buffs[i].buff;
+        buff.stacking() != Stacking::Queue || !self.buffs[..i].iter().any(|b| b.same_kind(buff))
+    }
+    fn active(&self) -> impl Iterator<Item = &Buff> {
+        (0..self.buffs.len())
+            .filter(|i| self.is_active(*i))
+            .map(|i| &self.buffs[i].buff)
+    }
+
+    fn consume(&mut self, consumption: Consumption) {
+        if !self.buffs.iter().any(|b| b.buff.consumption() == consumption) {
+            return;
+        }
+        let consumed: Vec<_> = (0..self.buffs.len())
+            .map(|i| self.is_active(i) && self.buffs[i].buff.consumption() == consumption)
+            .collect();
+        let mut consumed = consumed.into_iter();
+        self.buffs.retain(|_| !consumed.next().unwrap());
+        self.changed.set(true);
+    }
+
+    // dots that were inflicted by someone are reported like skills, so they show up next to the hits
+    pub fn tick(owner: &mut Combatant) -> Vec<SkillStats> {
+        if owner.buffs.buffs.is_empty() {
+            return Vec::new();
+        }
+        let active: Vec<_> = (0..owner.buffs.buffs.len()).map(|i| owner.buffs.is_active(i)).collect();
+
+        let dots: Vec<_> = owner.buffs.buffs.iter()
+            .zip(&active)
+            .filter(|(_, active)| **active)
+            .filter_map(|(b, _)| b.buff.dot(owner).map(|dmg| (b.buff.icon(), dmg, b.origin.clone())))
+            .collect();
+        let mut reports = Vec::new();
+        for (debuff, pre_res_damage, origin) in dots {
+            let pos_res_damage = hit::mitigation(pre_res_damage, owner.stats().resistances);
+            let absorbed = owner.absorbs(pos_res_damage.sum());
+            owner.events.push(CombatEvent::DotTick { target: owner.kind, debuff, damage: pos_res_damage.sum(), absorbed });
+            owner.damage(pos_res_damage.sum());
+            if let Some(BuffOrigin { applier, source, mod_id }) = origin {
+                let dot = DotStats { applier, target: owner.kind, debuff, damage: pos_res_damage, mod_id };
+                reports.push(SkillStats::Dot(source, dot));
+            }
+        }
+
+        owner.buffs.buffs.iter_mut()
+            .zip(active)
+            .filter(|(_, active)| *active)
+            .for_each(|(b, _)| if let Some(ticks) = &mut b.ticks {
+                *ticks -= 1;
+            });
+        let before = owner.buffs.buffs.len();
+        owner.buffs.buffs.retain(|b| b.ticks != Some(0));
+        if owner.buffs.buffs.len() != before {
+            owner.buffs.changed.set(true);
+        }
+        reports
+    }
+    pub fn has(&self, icon: BuffIcon) -> bool {
+        self.buffs.iter().any(|b| b.buff.icon() == icon)
+    }
+    pub fn debuff_count(&self) -> usize {
+        self.buffs.iter().filter(|b| b.buff.icon().is_debuff()).count()
+    }
+    pub(crate) fn take_changed(&self) -> bool {
+        self.changed.replace(false)
+    }
+    pub fn attacked(&mut self) {
+        self.consume(Consumption::Attacking);
+    }
+
+    pub fn icons(&self) -> impl Iterator<Item = BuffIcon> {
+        let mut icons = Vec::new();
+        for icon in self.buffs.iter().map(|b| b.buff.icon()) {
+            if !icons.contains(&icon) {
+                icons.push(icon);
+            }
+        }
+        icons.into_iter()
+    }
+    pub fn descriptions(&self) -> Vec<(BuffIcon, String)> {
+        // identical buffs are listed once with a counter
+        let mut out: Vec<(BuffIcon, String, usize)> = Vec::new();
+        for b in &self.buffs {
+            let icon = b.buff.icon();
+            let description = b.buff.describe(b.ticks);
+            match out.iter_mut().find(|(i, d, _)| *i == icon && *d == description) {
+                Some((_, _, count)) => *count += 1,
+                None => out.push((icon, description, 1)),
+            }
+        }
+        out.into_iter()
+            .map(|(icon, description, count)| match count {
+                1 => (icon, description),
+                _ => (icon, format!("{} (x{})", description, count)),
+            })
+            .collect()
+    }
+}
+impl Buffs {
+    pub fn apply_pre_hit(&self, attack: &mut PreHit, skill: &Skill, user: &Combatant, target: &Combatant) {
+        self.active().for_each(|b| b.apply_pre_hit(attack, skill, user, target));
+    }
+    pub fn apply_post_hit(&self, attack: &mut PostHit, skill: &Skill, user: &Combatant, target: &Combatant, hit: &Hit) {
+        self.active().for_each(|b| b.apply_post_hit(attack, skill, user, target, hit));
+    }
+    pub fn apply_pre_getting_hit(&mut self, attack: &mut PreHit) {
+        self.active().for_each(|b| b.apply_pre_getting_hit(attack));
+        self.consume(Consumption::Attacked);
+    }
+    pub fn apply_post_getting_hit(&mut self, attack: &mut PostHit) {
+        self.active().for_each(|b| b.apply_post_getting_hit(attack));
+        self.consume(Consumption::Hit);
+    }
+    pub fn apply_pre_atk(&self, resp: &mut ResponsePreHit, skill: &Skill, user: &Combatant, attacker: &Combatant) {
+        self.active().for_each(|b| b.apply_pre_atk(resp, skill, user, attacker));
+    }
+    pub fn apply_post_atk(&self, resp: &mut ResponsePostHit, skill: &Skill, user: &Combatant, attacker: &Combatant, hit: &Hit) {
+        self.active().for_each(|b| b.apply_post_atk(resp, skill, user, attacker, hit));
+    }
+    pub fn apply_to_def(&self, def: &mut Defend, skill: &Skill, user: &Combatant) {
+        self.active().for_each(|b| b.apply_to_def(def, skill, user));
+    }
+    pub fn apply_to_char(&self, char: &mut CharStats) {
+        self.active().for_each(|b| b.apply_to_char(char));
+    }
+}
+
+fn seconds(ticks: Option<u8>) -> f32 {
+    ticks.unwrap_or_default() as f32 / 10.
+}
+
+#[derive(Debug, Clone)]
+pub struct AttunedBuff {
+    value: f32,
+    element: Element,
+}
+impl Buff {
+    pub fn attuned(value: f32, element: Element) -> Self {
+        Self::Attuned(AttunedBuff { value, element })
+    }
+}
+impl BuffEffect for AttunedBuff {
+    fn icon(&self) -> BuffIcon {
+        BuffIcon::ResUp
+    }
+
+    fn apply_to_char(&self, char: &mut CharStats) {
+        *char.resistances.get_mut(self.element) += self.value;
+    }
+
+    fn describe(&self, _ticks: Option<u8>) -> String {
+        let res = Elemental::default().with(self.value, self.element);
+        format!("Add {}/{}/{}/{} Resistance", res.bleed, res.fracture, res.madness, res.void)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ReverbBuff {
+    damage: Elemental<f32>,
+}
+impl Buff {
+    pub fn reverb(damage: Elemental<f32>) -> Self {
+        Self::Reverb(ReverbBuff { damage })
+    }
+}
+impl BuffEffect for ReverbBuff {
+    fn icon(&self) -> BuffIcon {
+        BuffIcon::DmgUp
+    }
+    fn consumption(&self) -> Consumption {
+        Consumption::Attacking
+    }
+
+    fn apply_pre_hit(&self, attack: &mut PreHit, _skill: &Skill, _user: &Combatant, _target: &Combatant) {
+        attack.damage = attack.damage + self.damage;
+    }
+
+    fn describe(&self, _ticks: Option<u8>) -> String {
+        let damage = self.damage;
+        format!("Add {}/{}/{}/{} Damage to the next Attack", damage.bleed, damage.fracture, damage.madness, damage.void)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct EmpoweredBuff {
+    value: f32,
+}
+impl Buff {
+    pub fn empowered(value: f32) -> Self {
+        Self::Empowered(EmpoweredBuff { value })
+    }
+}
+impl BuffEffect for EmpoweredBuff {
+    fn icon(&self) -> BuffIcon {
+        BuffIcon::DmgUp
+    }
+    fn consumption(&self) -> Consumption {
+        Consumption::Attacking
+    }
+
+    fn apply_pre_hit(&self, attack: &mut PreHit, _skill: &Skill, _user: &Combatant, _target: &Combatant) {
+        attack.damage_mult = attack.damage_mult * (1. + self.value / 100.);
+    }
+
+    fn describe(&self, _ticks: Option<u8>) -> String {
+        format!("The next attack does {:.0}% more damage", self.value)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct HasteBuff {
+    value: f32,
+}
+impl Buff {
+    pub fn haste(value: f32) -> Self {
+        Self::Haste(HasteBuff { value })
+    }
+}
+impl BuffEffect for HasteBuff {
+    fn icon(&self) -> BuffIcon {
+        BuffIcon::Haste
+    }
+    fn duration(&self) -> Option<u8> {
+        Some(50)
+    }
+
+    // haste adds up, so two of them are as good as one twice as strong
+    fn apply_to_char(&self, char: &mut CharStats) {
+        char.speed += self.value / 100.;
+    }
+
+    fn describe(&self, ticks: Option<u8>) -> String {
+        format!("For {} seconds, cooldowns recover {:.0}% faster", seconds(ticks), self.value)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BleedDebuff {}
+impl Buff {
+    pub fn bleed() -> Self {
+        Self::Bleed(BleedDebuff {})
+    }
+}
+impl BuffEffect for BleedDebuff {
+    fn icon(&self) -> BuffIcon {
+        BuffIcon::Bleed
+    }
+    fn duration(&self) -> Option<u8> {
+        Some(20)
+    }
+    // only the first bleed debuff ticks
+    fn stacking(&self) -> Stacking {
+        Stacking::Queue
+    }
+
+    fn dot(&self, owner: &Combatant) -> Option<Elemental<f32>> {
+        let tick_damage = owner.stats().max_health / 100.0;
+        Some(Elemental { bleed: tick_damage, fracture: 0., madness: 0., void: 0.})
+    }
+
+    fn describe(&self, ticks: Option<u8>) -> String {
+        format!("Take 10% of your max heath as %bleed damage every second for {} seconds", seconds(ticks))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct VulnerableDebuff {}
+impl Buff {
+    pub fn vulnerable() -> Self {
+        Self::Vulnerable(VulnerableDebuff {})
+    }
+}
+impl BuffEffect for VulnerableDebuff {
+    fn icon(&self) -> BuffIcon {
+        BuffIcon::Vulnerable
+    }
+    fn consumption(&self) -> Consumption {
+        Consumption::Attacked
+    }
+
+    fn apply_pre_getting_hit(&self, attack: &mut PreHit) {
+        attack.damage_mult = attack.damage_mult * 1.3;
+    }
+
+    fn describe(&self, _ticks: Option<u8>) -> String {
+        "The next attack against you does 30% more damage".to_string()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ConfusedDebuff {}
+impl Buff {
+    pub fn confused() -> Self {
+        Self::Confused(ConfusedDebuff {})
+    }
+}
+impl BuffEffect for ConfusedDebuff {
+    fn icon(&self) -> BuffIcon {
+        BuffIcon::Confused
+    }
+    // one confusion per attack
+    fn stacking(&self) -> Stacking {
+        Stacking::Queue
+    }
+    fn consumption(&self) -> Consumption {
+        Consumption::Attacking
+    }
+
+    fn apply_post_hit(&self, attack: &mut PostHit, _skill: &Skill, _user: &Combatant, _target: &Combatant, _hit: &Hit) {
+        attack.self_hit = true;
+    }
+
+    fn describe(&self, _ticks: Option<u8>) -> String {
+        "Your next attack will also damage yourself".to_string()
+    }
+}
+
+const ECHO_TICKS: u8 = 100;
+
+#[derive(Debug, Clone)]
+pub struct EchoDebuff {
+    tick_damage: Elemental<f32>,
+}
+impl Buff {
+    pub fn echo(damage: Elemental<f32>) -> Self {
+        Self::Echo(EchoDebuff { tick_damage: damage / ECHO_TICKS as f32 })
+    }
+}
+impl BuffEffect for EchoDebuff {
+    fn icon(&self) -> BuffIcon {
+        BuffIcon::Echo
+    }
+    fn duration(&self) -> Option<u8> {
+        Some(ECHO_TICKS)
+    }
+
+    fn dot(&self, _owner: &Combatant) -> Option<Elemental<f32>> {
+        Some(self.tick_damage)
+    }
+
+    fn describe(&self, ticks: Option<u8>) -> String {
+        format!("Take {} damage over {} seconds", self.tick_damage.sum() * ticks.unwrap_or_default() as f32, seconds(ticks))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LifelinkDebuff {}
+impl Buff {
+    pub fn lifelink() -> Self {
+        Self::Lifelink(LifelinkDebuff {})
+    }
+}
+impl BuffEffect for LifelinkDebuff {
+    fn icon(&self) -> BuffIcon {
+        BuffIcon::Lifelink
+    }
+    fn consumption(&self) -> Consumption {
+        Consumption::Hit
+    }
+
+    fn apply_post_getting_hit(&self, attack: &mut PostHit) {
+        attack.life_steal += 0.2;
+    }
+
+    fn describe(&self, _ticks: Option<u8>) -> String {
+        "The next attack against you has an additional 20% life steal".to_string()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IncapacitatedDebuff {}
+impl Buff {
+    pub fn incapacitated() -> Self {
+        Self::Incapacitated(IncapacitatedDebuff {})
+    }
+}
+impl BuffEffect for IncapacitatedDebuff {
+    fn icon(&self) -> BuffIcon {
+        BuffIcon::Incapacitated
+    }
+    fn duration(&self) -> Option<u8> {
+        Some(20)
+    }
+    // only the first incapacitated debuff counts down
+    fn stacking(&self) -> Stacking {
+        Stacking::Queue
+    }
+
+    fn apply_to_char(&self, char: &mut CharStats) {
+        char.speed *= 0.5;
+    }
+
+    fn describe(&self, ticks: Option<u8>) -> String {
+        format!("For {} seconds, cooldowns recover half as fast", seconds(ticks))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DazedDebuff {}
+impl Buff {
+    pub fn dazed() -> Self {
+        Self::Dazed(DazedDebuff {})
+    }
+}
+impl BuffEffect for DazedDebuff {
+    fn icon(&self) -> BuffIcon {
+        BuffIcon::Dazed
+    }
+    // one daze per attack
+    fn stacking(&self) -> Stacking {
+        Stacking::Queue
+    }
+    fn consumption(&self) -> Consumption {
+        Consumption::Attacking
+    }
+
+    fn apply_pre_hit(&self, attack: &mut PreHit, _skill: &Skill, _user: &Combatant, _target: &Combatant) {
+        attack.damage_mult = attack.damage_mult * 0.5;
+    }
+
+    fn describe(&self, _ticks: Option<u8>) -> String {
+        "Your next attack will deal 50% damage".to_string()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SoullinkDebuff {}
+impl Buff {
+    pub fn soullink() -> Self {
+        Self::Soullink(SoullinkDebuff {})
+    }
+}
+impl BuffEffect for SoullinkDebuff {
+    fn icon(&self) -> BuffIcon {
+        BuffIcon::Soullink
+    }
+    fn consumption(&self) -> Consumption {
+        Consumption::Hit
+    }
+
+    fn apply_post_getting_hit(&self, attack: &mut PostHit) {
+        attack.shield_steal += 0.25;
+    }
+
+    fn describe(&self, _ticks: Option<u8>) -> String {
+        "The next attack against you has an additional 25% shield steal".to_string()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SlowDebuff {
+    value: f32,
+}
+impl Buff {
+    pub fn slow(value: f32) -> Self {
+        Self::Slow(SlowDebuff { value })
+    }
+}
+impl BuffEffect for SlowDebuff {
+    fn icon(&self) -> BuffIcon {
+        BuffIcon::Slow
+    }
+    fn duration(&self) -> Option<u8> {
+        Some(30)
+    }
+
+    // slows multiply, so stacking them never stops the owner completely
+    fn apply_to_char(&self, char: &mut CharStats) {
+        char.speed *= 1. - self.value / 100.;
+    }
+
+    fn describe(&self, ticks: Option<u8>) -> String {
+        format!("For {} seconds, cooldowns recover {:.0}% slower", seconds(ticks), self.value)
+    }
+}
+
+#[apply(UnitEnum)]
+pub enum BuffIcon {
+    DmgUp,
+    ResUp,
+    ResDown,
+
+    Bleed,
+    Vulnerable,
+    Confused,
+    Echo,
+
+    Lifelink,
+    Incapacitated,
+    Dazed,
+    Soullink,
+
+    // added later, at the end to keep the saved ones stable
+    Haste,
+    Slow,
+}
+impl BuffIcon {
+    // the debuffs something can inflict, nothing lowers resistances yet
+    pub const DEBUFFS: [BuffIcon; 9] = [
+        BuffIcon::Bleed, BuffIcon::Vulnerable, BuffIcon::Confused, BuffIcon::Echo,
+        BuffIcon::Lifelink, BuffIcon::Incapacitated, BuffIcon::Dazed, BuffIcon::Soullink,
+        BuffIcon::Slow,
+    ];
+    pub fn is_debuff(&self) -> bool {
+        use BuffIcon::*;
+        matches!(self, ResDown | Bleed | Vulnerable | Confused | Echo | Lifelink | Incapacitated | Dazed | Soullink | Slow)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{combat::skill::hit::{PostHit, PreHit}, equipment::equipment::FighterEquip};
+
+    use super::*;
+
+    fn owner() -> Combatant {
+        Combatant::fighter(&FighterEquip::default())
+    }
+    fn active_ticks(buffs: &Buffs) -> Vec<Option<u8>> {
+        (0..buffs.buffs.len()).filter(|i| buffs.is_active(*i)).map(|i| buffs.buffs[i].ticks).collect()
+    }
+
+    #[test]
+    fn stacked_buffs_are_all_active() {
+        let mut buffs = Buffs::default();
+        buffs.add(Buff::attuned(10., Element::Bleed), None);
+        buffs.add(Buff::attuned(10., Element::Bleed), None);
+
+        let mut char = owner().stats();
+        let before = char.resistances.bleed;
+        buffs.apply_to_char(&mut char);
+        assert_eq!(char.resistances.bleed, before + 20.);
+    }
+
+    #[test]
+    fn queued_buffs_wait_for_the_first_to_run_out() {
+        let mut owner = owner();
+        owner.buffs.add(Buff::bleed(), None);
+        Buffs::tick(&mut owner);
+        owner.buffs.add(Buff::bleed(), None);
+        assert_eq!(active_ticks(&owner.buffs), vec![Some(19)]);
+
+        // the second bleed only starts counting down once it is active
+        for _ in 0..19 {
+            Buffs::tick(&mut owner);
+        }
+        assert_eq!(active_ticks(&owner.buffs), vec![Some(20)]);
+    }
+
+    #[test]
+    fn refreshed_buffs_replace_the_old_one() {
+        let mut owner = owner();
+        owner.buffs.add_stacking(Buff::bleed(), None, Stacking::Refresh);
+        for _ in 0..5 {
+            Buffs::tick(&mut owner);
+        }
+        owner.buffs.add_stacking(Buff::bleed(), None, Stacking::Refresh);
+        assert_eq!(owner.buffs.buffs.len(), 1);
+        assert_eq!(active_ticks(&owner.buffs), vec![Some(20)]);
+    }
+
+    #[test]
+    fn buffs_are_consumed_by_their_trigger_only() {
+        let mut buffs = Buffs::default();
+        buffs.add(Buff::attuned(10., Element::Bleed), None); // Never
+        buffs.add(Buff::empowered(10.), None);               // Attacking
+        buffs.add(Buff::vulnerable(), None);                 // Attacked
+        buffs.add(Buff::lifelink(), None);                   // Hit
+        let icons = |buffs: &Buffs| buffs.icons().collect::<Vec<_>>();
+
+        buffs.apply_post_getting_hit(&mut PostHit::default());
+        assert_eq!(icons(&buffs), vec![BuffIcon::ResUp, BuffIcon::DmgUp, BuffIcon::Vulnerable]);
+        buffs.apply_pre_getting_hit(&mut PreHit::default());
+        assert_eq!(icons(&buffs), vec![BuffIcon::ResUp, BuffIcon::DmgUp]);
+        buffs.attacked();
+        assert_eq!(icons(&buffs), vec![BuffIcon::ResUp]);
+    }
+
+    #[test]
+    fn consuming_a_queue_only_takes_the_active_one() {
+        let mut buffs = Buffs::default();
+        buffs.add(Buff::confused(), None);
+        buffs.add(Buff::confused(), None);
+
+        buffs.attacked();
+        assert_eq!(buffs.buffs.len(), 1);
+        buffs.attacked();
+        assert!(buffs.buffs.is_empty());
+    }
+}
@@ -1,31 +1,40 @@
 use std::mem;
 
-use crate::combat::buff::Buffs;
+use enumset::EnumSet;
+
+use crate::combat::buff::{Buff, BuffEffect, BuffOrigin, Buffs, Debuff};
+use crate::combat::event::{CombatEvent, EventSink};
 use crate::combat::hooks::CombatHooks;
-use crate::elemental::Element;
-use crate::equipment::equipment::{Equip, EquipEnum, FighterEquip};
+use crate::combat::rng::CombatRng;
+use crate::combat::threat::ThreatTable;
+use crate::dungeon::modifier::DungeonModifier;
+use crate::equipment::equipment::{Equip, EquipEnum, FighterEquip, MageEquip, RangerEquip};
 use crate::prelude::*;
 
-use crate::{elemental::Elemental, panels::animation::Animation};
+use crate::elemental::Elemental;
 
 use super::skill::hit::{Hit, PreHit, ResponsePostHit, ResponsePreHit};
 use super::skill::skill::{SkillSource, SkillStats};
 use super::skill::targeting::Targeting;
 use super::{
-    enemy::EnemyKind,
+    enemy::{EnemyAffinity, EnemyKind},
     skill::skill::{Skill, SkillKind},
 };
 
 #[apply(Enum)]
-#[derive(Copy)]
+#[derive(Copy, PartialEq)]
 pub enum CombatantKind {
     Fighter,
-    // Ranger,
-    // Mage,
+    Ranger,
+    Mage,
     Enemy(u8, EnemyKind),
 }
 
-#[derive(Debug)]
+// per tick, so the recent damage halves a bit over every 2 seconds
+const RECENT_DAMAGE_FADE: f32 = 0.97;
+
+// a clone still shares the events and rng of the battle it was in, Battle hands out its own
+#[derive(Debug, Clone)]
 pub struct Combatant {
     pub kind: CombatantKind,
 
@@ -35,12 +44,25 @@ pub struct Combatant {
     pub buffs: Buffs,
     pub skills: Vec<Skill>,
     pub hooks: CombatHooks,
+    pub threat: ThreatTable,
+    pub events: EventSink,
+    pub rng: CombatRng,
+    pub affinity: Option<EnemyAffinity>,
+    // damage dealt with hits, fading over a few seconds
+    pub recent_damage: f32,
+    pub last_target: Option<CombatantKind>,
+    // fills up by speed every tick, every full point lets the skills recover one tick of cooldown
+    speed_meter: f32,
+    cached_stats: Cell<Option<CharStats>>,
 }
 
 impl Combatant {
     pub fn transfer(&mut self) {
         self.shield = 0.;
         self.buffs = Buffs::default();
+        self.recent_damage = 0.;
+        self.last_target = None;
+        self.speed_meter = 0.;
         self.skills.iter_mut().for_each(|s| s.transfer());
     }
 
@@ -79,34 +101,80 @@ impl Combatant {
             buffs: Buffs::default(),
             skills,
             hooks,
+            threat: ThreatTable::default(),
+            events: EventSink::default(),
+            rng: CombatRng::default(),
+            affinity: None,
+            recent_damage: 0.,
+            last_target: None,
+            speed_meter: 0.,
+            cached_stats: Cell::new(None),
         };
         explorer.health = explorer.stats().max_health;
         explorer
     }
 
-    // ranger
-    // mage
+    pub fn ranger(equip: &RangerEquip) -> Self {
+        let equip_enum = equip.clone().into();
+        let skills = [
+            &equip.weapons[0],
+            &equip.weapons[1],
+            &equip.satchel,
+            &equip.common.helmet,
+        ]
+        .into_iter()
+        .filter_map(|i| i.upgrade())
+        .filter_map(|i| Skill::from_item(i, &equip_enum))
+        .collect();
 
-    pub fn enemy(kind: EnemyKind, i: u8, depth: u16, rng: &mut impl Rng) -> Self {
+        Self::explorer(CombatantKind::Ranger, skills, equip_enum)
+    }
+
+    pub fn mage(equip: &MageEquip) -> Self {
+        let equip_enum = equip.clone().into();
+        let skills = [
+            &equip.weapons[0],
+            &equip.weapons[1],
+            &equip.support_gem,
+            &equip.staff,
+            &equip.common.helmet,
+        ]
+        .into_iter()
+        .filter_map(|i| i.upgrade())
+        .filter_map(|i| Skill::from_item(i, &equip_enum))
+        .collect();
+
+        Self::explorer(CombatantKind::Mage, skills, equip_enum)
+    }
+
+    pub fn enemy(kind: EnemyKind, affinity: EnemyAffinity, i: u8, depth: u16, modifiers: EnumSet<DungeonModifier>, rng: &mut impl Rng) -> Self {
         let mut hooks = CombatHooks::default();
         hooks.on_pre_hit(move |attack: &mut PreHit, _skill: &Skill, _user: &Combatant, _target: &Combatant| {
             attack.penetration = attack.penetration + depth as f32;
         });
-        let weakness = *Element::VARIANTS.pick(rng);
+        let weakness = affinity.weakness;
         hooks.on_char(move |char: &mut CharStats| {
             char.resistances = char.resistances + (depth as f32) / 2.;
             char.resistances.set(char.resistances.get(weakness) - 10., weakness);
         });
-        
-        let damage_type = *Element::VARIANTS.pick(rng);
+        modifiers.iter().for_each(|m| m.register(&mut hooks));
+
         let mut enemy = Self {
             kind: CombatantKind::Enemy(i, kind),
             health: 0.,
             wounds: 0.,
             shield: 0.,
             buffs: Buffs::default(),
-            skills: kind.etype().skills(damage_type),
+            skills: kind.skills(affinity.damage_type),
             hooks,
+            threat: ThreatTable::default(),
+            events: EventSink::default(),
+            rng: CombatRng::default(),
+            affinity: Some(affinity),
+            recent_damage: 0.,
+            last_target: None,
+            speed_meter: 0.,
+            cached_stats: Cell::new(None),
         };
         enemy.health = enemy.stats().max_health;
 
@@ -119,10 +187,12 @@ impl Combatant {
 impl Combatant {
     pub fn combat_start(&mut self) {
         // add delay to off hand
-        self.skills.iter_mut()
+        if let Some(off_hand) = self.skills.iter_mut()
             .filter(|s| s.kind() == SkillKind::Attack)
             .nth(1)
-            .map(|s| s.cd += 10);
+        {
+            off_hand.cd += 10;
+        }
 
         let mut effects = CombatStartEffects::default();
         self.hooks.combat_start(&mut effects, self);
@@ -131,7 +201,9 @@ impl Combatant {
         let stats = self.stats();
         self.shield(effects.shield_from_max_health * stats.max_health);
         for ready_skill in effects.ready_skills {
-            self.find_skill_mut(ready_skill).map(|s| s.cd = 0);
+            if let Some(s) = self.find_skill_mut(ready_skill) {
+                s.cd = 0;
+            }
         }
     }
 
@@ -152,41 +224,46 @@ impl Combatant {
         })
     }
 
-    // TODO cache Stats
+    // cached until the buffs change, the hooks are only set at construction
     pub fn stats(&self) -> CharStats {
+        if self.buffs.take_changed() {
+            self.cached_stats.set(None);
+        }
+        if let Some(char) = self.cached_stats.get() {
+            return char;
+        }
+
         let mut char = self.kind.stats();
         self.hooks.char(&mut char);
         self.buffs.apply_to_char(&mut char);
+        self.cached_stats.set(Some(char));
         char
     }
 
     pub fn tick<'a>(
         &mut self,
-        tick: u64,
         mut allies: Vec<&'a mut Combatant>,
         mut enemies: Vec<&'a mut Combatant>,
-    ) -> Option<SkillStats> {
-        Buffs::tick(self);
-
-        let stats = self.stats();
-
-        if tick % (stats.tick_rate as u64) != 0 {
-            return None;
+    ) -> Vec<SkillStats> {
+        let mut skills = Buffs::tick(self);
+        self.recent_damage *= RECENT_DAMAGE_FADE;
+
+        self.speed_meter += self.stats().speed;
+        while self.speed_meter >= 1. {
+            self.speed_meter -= 1.;
+            self.skills.iter_mut().for_each(|s| s.tick());
         }
 
-        self.skills.iter_mut().for_each(|s| s.tick());
-
         if self.skills.iter().any(|s| s.ready()) {
-            self.trigger_skill(&mut allies, &mut enemies, true)
-        } else {
-            None
+            skills.extend(self.trigger_skill(&mut allies, &mut enemies, true));
         }
+        skills
     }
 
     pub fn trigger_skill<'a, 'b>(
         &mut self,
-        allies: &'a mut Vec<&'b mut Combatant>,
-        enemies: &'a mut Vec<&'b mut Combatant>,
+        allies: &'a mut [&'b mut Combatant],
+        enemies: &'a mut [&'b mut Combatant],
         reset_cooldown: bool,
     ) -> Option<SkillStats> {
         let mut skills = mem::take(&mut self.skills);
@@ -194,8 +271,7 @@ impl Combatant {
         let ready = skills
             .iter_mut()
             .filter(|s| s.ready())
-            .filter(|s| s.targeting != Targeting::OnAttack)
-            .next();
+            .find(|s| s.triggers(self, allies));
 
         let stats = ready.map(|s| s.trigger(self, allies, enemies, reset_cooldown));
 
@@ -209,8 +285,7 @@ impl Combatant {
         let ready = skills
             .iter_mut()
             .filter(|s| s.ready())
-            .filter(|s| s.targeting == Targeting::OnAttack)
-            .next();
+            .find(|s| s.targeting == Targeting::OnAttack);
 
         let resp = ready.map(|skill| {
             let stats = skill.trigger_against_target(self, attacker, reset_cooldown);
@@ -242,33 +317,35 @@ impl Combatant {
 
     pub fn trigger_attack_against_target(&mut self, target: &mut Combatant, reset_cooldown: bool) -> Option<SkillStats> {
         let mut skills = mem::take(&mut self.skills);
-        let skill = skills.iter_mut().filter(|s| s.kind().is_attack()).next();
+        let skill = skills.iter_mut().find(|s| s.kind().is_attack());
         let stats = skill.map(|s| s.trigger_against_target(self, target, reset_cooldown));
         self.skills = skills; // TODO why has rust no defer???
         stats
     }
 
-    pub fn idle_animation(&self) -> Animation {
-        match &self.kind {
-            CombatantKind::Fighter => Animation::FighterIdle,
-            CombatantKind::Enemy(_, enemy_kind) => enemy_kind.idle_animation(),
-        }
-    }
-    pub fn attack_animation(&self) -> Animation {
-        match &self.kind {
-            CombatantKind::Fighter => Animation::FighterAttack,
-            CombatantKind::Enemy(_, enemy_kind) => enemy_kind.attack_animation(),
-        }
-    }
-
     pub fn damage(&mut self, amount: f32) {
         let char = self.stats();
-        let shield_dmg = (amount * 0.75).at_most(self.shield);
+        let shield_dmg = self.absorbs(amount);
         let health_dmg = amount - shield_dmg;
 
         self.health -= health_dmg.at_most(self.health);
-        self.wounds += (health_dmg / 4.).at_most(char.max_health - self.wounds);
+        self.wounds += (health_dmg * char.wound_conversion).at_most(char.max_health - self.wounds);
         self.shield -= shield_dmg;
+        if shield_dmg > 0. && self.shield <= 0. {
+            self.events.push(CombatEvent::ShieldBroken { target: self.kind });
+        }
+    }
+    // how much of the damage the shield would take
+    pub fn absorbs(&self, amount: f32) -> f32 {
+        (amount * 0.75).at_most(self.shield)
+    }
+    pub fn add_buff(&mut self, buff: Buff) {
+        self.events.push(CombatEvent::BuffApplied { target: self.kind, buff: buff.icon() });
+        self.buffs.add(buff, None);
+    }
+    pub fn inflict(&mut self, debuff: Debuff, origin: BuffOrigin) {
+        self.events.push(CombatEvent::BuffApplied { target: self.kind, buff: debuff.icon() });
+        self.buffs.add(debuff, Some(origin));
     }
     pub fn heal(&mut self, amount: f32) -> f32 {
         let char = self.stats();
@@ -277,6 +354,15 @@ impl Combatant {
         self.health += healed;
         healed
     }
+    // recovery between battles, unlike heal this ignores heal power
+    pub fn recover(&mut self, percent: f32) {
+        let char = self.stats();
+        self.health += (char.max_health * percent).at_most(char.max_health - self.wounds - self.health);
+    }
+    pub fn mend_wounds(&mut self) {
+        let char = self.stats();
+        self.wounds -= self.wounds * char.wound_mending.at_most(1.);
+    }
     pub fn shield(&mut self, amount: f32) -> f32 {
         let char = self.stats();
         let total_shield = amount * char.shield_power;
@@ -284,31 +370,66 @@ impl Combatant {
         total_shield
     }
 }
+impl std::fmt::Display for CombatantKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CombatantKind::Fighter => write!(f, "Fighter"),
+            CombatantKind::Ranger => write!(f, "Ranger"),
+            CombatantKind::Mage => write!(f, "Mage"),
+            CombatantKind::Enemy(i, enemy_kind) => write!(f, "{:?} #{}", enemy_kind, i + 1),
+        }
+    }
+}
 impl CombatantKind {
     fn stats(&self) -> CharStats {
         match self {
-            CombatantKind::Fighter => CharStats {
-                max_health: 500.,
-                resistances: Default::default(),
-                heal_power: 1.0,
-                shield_power: 1.0,
-                cdr: 0,
-                tick_rate: 1,
-            },
+            CombatantKind::Fighter => CharStats { max_health: 500., ..CharStats::base() },
+            CombatantKind::Ranger => CharStats { max_health: 350., ..CharStats::base() },
+            CombatantKind::Mage => CharStats { max_health: 300., ..CharStats::base() },
             CombatantKind::Enemy(_, enemy_kind) => enemy_kind.etype().stats(),
         }
     }
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Copy, Debug)]
 pub struct CharStats {
     pub max_health: f32,
     pub resistances: Elemental<f32>,
 
     pub heal_power: f32,
     pub shield_power: f32,
+    // chances are 0 to 1, a crit multiplies the damage of the hit
+    pub crit_chance: f32,
+    pub crit_multi: f32,
+    pub evasion: f32,
+    // part of the health damage taken that turns into wounds, which cap healing
+    pub wound_conversion: f32,
+    // part of max health healed while walking to the next battle
+    pub recovery: f32,
+    // part of the wounds mended when entering a new floor
+    pub wound_mending: f32,
     pub cdr: u16,
-    pub tick_rate: u8,
+    // 1 is normal speed, skills recover their cooldowns that much faster or slower
+    pub speed: f32,
+}
+impl CharStats {
+    // what every combatant starts from, each kind sets its own max health on top
+    pub fn base() -> Self {
+        Self {
+            max_health: 0.,
+            resistances: Default::default(),
+            heal_power: 1.0,
+            shield_power: 1.0,
+            crit_chance: 0.,
+            crit_multi: 1.5,
+            evasion: 0.,
+            wound_conversion: 0.25,
+            recovery: 0.1,
+            wound_mending: 0.25,
+            cdr: 0,
+            speed: 1.,
+        }
+    }
 }
 
 #[apply(Default)]
@@ -0,0 +1,160 @@
+use crate::{combat::{buff::Buff, combatant::{CharStats, Combatant}, hooks::CombatHooks, skill::hit::{Hit, PostHit, PreHit}}, elemental::{Element, Elemental}, prelude::*};
+use EnemyType::*;
+use EnemyKind::*;
+
+use crate::combat::skill::skill::{Skill, SkillKind};
+
+#[apply(UnitEnum)]
+pub enum EnemyType {
+    Small,
+    Medium,
+    Tank,
+    Dps,
+    Boss,
+}
+#[apply(UnitEnum)]
+pub enum EnemyKind {
+    Bat,
+    BigWorn,
+    Minotaur,
+    Orc,
+    Skeleton,
+    Spider,
+    BigSpider,
+
+    MinotaurKing,
+    BroodMother,
+    BoneLord,
+}
+
+impl From<EnemyKind> for EnemyType {
+    fn from(value: EnemyKind) -> Self {
+        match value {
+            Bat | Spider => Small,
+            Skeleton | Orc => Medium,
+            Minotaur => Tank,
+            BigWorn | BigSpider => Dps,
+            MinotaurKing | BroodMother | BoneLord => Boss,
+        }
+    }
+}
+impl EnemyType {
+    pub fn variants(self) -> &'static [EnemyKind] {
+        match self {
+            Small  => &[Bat, Spider],
+            Medium => &[Skeleton, Orc],
+            Tank   => &[Minotaur],
+            Dps    => &[BigWorn, BigSpider],
+            Boss   => &[MinotaurKing, BroodMother, BoneLord],
+        }
+    }
+    pub fn stats(&self) -> CharStats {
+        match self {
+            Small => CharStats { max_health: 100., ..CharStats::base() },
+            Medium => CharStats { max_health: 500., ..CharStats::base() },
+            Tank => CharStats {
+                max_health: 1500.,
+                resistances: Elemental::from(10.),
+                heal_power: 1.5,
+                shield_power: 1.5,
+                ..CharStats::base()
+            },
+            Dps => CharStats { max_health: 250., ..CharStats::base() },
+            Boss => CharStats {
+                max_health: 4000.,
+                resistances: Elemental::from(20.),
+                ..CharStats::base()
+            },
+        }
+    }
+}
+
+// the weakness has 10 less resistance, the damage type is what the skills deal
+#[derive(Debug, Clone, Copy)]
+pub struct EnemyAffinity {
+    pub weakness: Element,
+    pub damage_type: Element,
+}
+impl EnemyAffinity {
+    pub fn random(rng: &mut impl Rng) -> Self {
+        Self { weakness: *Element::VARIANTS.pick(rng), damage_type: *Element::VARIANTS.pick(rng) }
+    }
+}
+
+impl EnemyKind {
+    pub fn etype(self) -> EnemyType {
+        self.into()
+    }
+
+    // the first ready skill in the list gets used, so signature moves go in front of the basic attack
+    // skills gated behind a health threshold only join once the user gets below it, for bosses that's the next phase.
+    // the gate only decides when, a gated attack still hits whoever the threat order puts first
+    pub fn skills(self, damage_type: Element) -> Vec<Skill> {
+        match self {
+            MinotaurKing => vec![
+                Skill::from_enemy(SkillKind::Attack, 15, |hooks| add_damage(hooks, 50., damage_type)).below_health(50),
+                Skill::from_enemy(SkillKind::AoeAttack, 70, |hooks| add_damage(hooks, 50., damage_type)),
+                Skill::from_enemy(SkillKind::Attack, 40, |hooks| add_damage(hooks, 80., damage_type)),
+            ],
+            BroodMother => vec![
+                Skill::from_enemy(SkillKind::AoeAttack, 30, |hooks| { add_damage(hooks, 30., damage_type); add_debuff(hooks, Buff::bleed); }).below_health(40),
+                Skill::from_enemy(SkillKind::AoeAttack, 60, |hooks| { add_damage(hooks, 30., damage_type); add_debuff(hooks, Buff::bleed); }),
+                Skill::from_enemy(SkillKind::Attack, 25, |hooks| add_damage(hooks, 50., damage_type)),
+            ],
+            BoneLord => vec![
+                Skill::from_enemy(SkillKind::AoeAttack, 40, |hooks| { add_damage(hooks, 60., damage_type); add_pen(hooks, 10.); }).below_health(30),
+                Skill::from_enemy(SkillKind::Defend, 80, |hooks| add_shield(hooks, 400.)).below_health(60),
+                Skill::from_enemy(SkillKind::Attack, 30, |hooks| add_damage(hooks, 70., damage_type)),
+            ],
+            Bat => vec![
+                Skill::from_enemy(SkillKind::Attack, 25, |hooks| { add_damage(hooks, 20., damage_type); add_life_steal(hooks, 0.25); }),
+            ],
+            Spider => vec![
+                Skill::from_enemy(SkillKind::Attack, 100, |hooks| { add_damage(hooks, 10., damage_type); add_debuff(hooks, Buff::bleed); }),
+                Skill::from_enemy(SkillKind::Attack, 25, |hooks| add_damage(hooks, 15., damage_type)),
+            ],
+            Skeleton => vec![
+                Skill::from_enemy(SkillKind::Defend, 60, |hooks| add_shield(hooks, 150.)).below_health(75),
+                Skill::from_enemy(SkillKind::Attack, 25, |hooks| add_damage(hooks, 40., damage_type)),
+            ],
+            Minotaur => vec![
+                Skill::from_enemy(SkillKind::AoeAttack, 80, |hooks| add_damage(hooks, 40., damage_type)),
+                Skill::from_enemy(SkillKind::Attack, 50, |hooks| add_damage(hooks, 60., damage_type)),
+            ],
+            BigSpider => vec![
+                Skill::from_enemy(SkillKind::Attack, 60, |hooks| { add_damage(hooks, 30., damage_type); add_debuff(hooks, Buff::bleed); }),
+                Skill::from_enemy(SkillKind::Attack, 20, |hooks| { add_damage(hooks, 60., damage_type); add_pen(hooks, 10.); }),
+            ],
+            Orc => vec![
+                Skill::from_enemy(SkillKind::Attack, 25, |hooks| add_damage(hooks, 40., damage_type)),
+            ],
+            BigWorn => vec![
+                Skill::from_enemy(SkillKind::Attack, 20, |hooks| { add_damage(hooks, 60., damage_type); add_pen(hooks, 10.); }),
+            ],
+        }
+    }
+}
+
+fn add_damage(hooks: &mut CombatHooks, damge: f32, damage_type: Element) {
+    hooks.on_pre_hit(move |attack: &mut PreHit, _skill: &Skill, _user: &Combatant, _target: &Combatant| {
+        attack.damage.set(attack.damage.get(damage_type) + damge, damage_type);
+    });
+}
+fn add_pen(hooks: &mut CombatHooks, pen: f32) {
+    hooks.on_pre_hit(move |attack: &mut PreHit, _skill: &Skill, _user: &Combatant, _target: &Combatant| {
+        attack.penetration = attack.penetration + pen;
+    });
+}
+fn add_debuff(hooks: &mut CombatHooks, debuff: fn() -> Buff) {
+    hooks.on_pre_hit(move |attack: &mut PreHit, _skill: &Skill, _user: &Combatant, _target: &Combatant| {
+        attack.debuffs.push(debuff());
+    });
+}
+fn add_life_steal(hooks: &mut CombatHooks, life_steal: f32) {
+    hooks.on_post_hit(move |attack: &mut PostHit, _skill: &Skill, _user: &Combatant, _target: &Combatant, _hit: &Hit| {
+        attack.life_steal += life_steal;
+    });
+}
+fn add_shield(hooks: &mut CombatHooks, shield: f32) {
+    hooks.on_defend(move |def, _skill, _user| def.shield += shield);
+}
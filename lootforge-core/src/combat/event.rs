@@ -0,0 +1,128 @@
+use std::{fmt, mem};
+
+use crate::{elemental::Elemental, prelude::*};
+
+use super::{buff::BuffIcon, combatant::CombatantKind};
+
+// everything that happened in a battle that SkillStats don't tell, in the order it happened
+#[apply(Enum)]
+#[derive(Copy, strum::IntoStaticStr)]
+pub enum CombatEvent {
+    // absorbed is the part of the damage that went into the shield
+    Hit { attacker: CombatantKind, target: CombatantKind, damage: Elemental<f32>, absorbed: f32, crit: bool },
+    Blocked { attacker: CombatantKind, target: CombatantKind },
+    Evaded { attacker: CombatantKind, target: CombatantKind },
+    Culled { attacker: CombatantKind, target: CombatantKind },
+    Healed { source: CombatantKind, target: CombatantKind, amount: f32 },
+    Shielded { source: CombatantKind, target: CombatantKind, amount: f32 },
+    DotTick { target: CombatantKind, debuff: BuffIcon, damage: f32, absorbed: f32 },
+    BuffApplied { target: CombatantKind, buff: BuffIcon },
+    ShieldBroken { target: CombatantKind },
+    Died { target: CombatantKind },
+}
+
+#[apply(UnitEnum)]
+pub enum EventCategory {
+    Damage,
+    Defense,
+    Dot,
+    Buff,
+    Death,
+}
+
+impl CombatEvent {
+    pub fn category(&self) -> EventCategory {
+        use CombatEvent::*;
+        match self {
+            Hit { .. } | Culled { .. } => EventCategory::Damage,
+            Blocked { .. } | Evaded { .. } | Healed { .. } | Shielded { .. } | ShieldBroken { .. } => EventCategory::Defense,
+            DotTick { .. } => EventCategory::Dot,
+            BuffApplied { .. } => EventCategory::Buff,
+            Died { .. } => EventCategory::Death,
+        }
+    }
+
+    pub fn target(&self) -> CombatantKind {
+        use CombatEvent::*;
+        match *self {
+            Hit { target, .. } | Blocked { target, .. } | Evaded { target, .. } | Culled { target, .. } | Healed { target, .. } | Shielded { target, .. }
+                | DotTick { target, .. } | BuffApplied { target, .. } | ShieldBroken { target } | Died { target } => target,
+        }
+    }
+
+    pub fn source(&self) -> Option<CombatantKind> {
+        use CombatEvent::*;
+        match *self {
+            Hit { attacker, .. } | Blocked { attacker, .. } | Evaded { attacker, .. } | Culled { attacker, .. } => Some(attacker),
+            Healed { source, .. } | Shielded { source, .. } => Some(source),
+            DotTick { .. } | BuffApplied { .. } | ShieldBroken { .. } | Died { .. } => None,
+        }
+    }
+
+    pub fn amount(&self) -> Option<f32> {
+        use CombatEvent::*;
+        match *self {
+            Hit { damage, .. } => Some(damage.sum()),
+            Healed { amount, .. } | Shielded { amount, .. } => Some(amount),
+            DotTick { damage, .. } => Some(damage),
+            Blocked { .. } | Evaded { .. } | Culled { .. } | BuffApplied { .. } | ShieldBroken { .. } | Died { .. } => None,
+        }
+    }
+
+    pub const CSV_HEADER: &str = "tick,category,event,source,target,amount";
+
+    pub fn csv(&self, tick: u64) -> String {
+        let event: &'static str = self.into();
+        let category: &'static str = self.category().into();
+        format!(
+            "{},{},{},{},{},{}",
+            tick,
+            category,
+            event,
+            self.source().map_or(String::new(), |s| s.to_string()),
+            self.target(),
+            self.amount().map_or(String::new(), |a| format!("{:.1}", a)),
+        )
+    }
+}
+
+impl fmt::Display for CombatEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use CombatEvent::*;
+        match self {
+            Hit { attacker, target, damage, .. } if attacker == target => write!(f, "{} hits themself for {:.0}", attacker, damage.sum()),
+            Hit { attacker, target, damage, crit, .. } => write!(
+                f, "{} {} {} for {:.0} ({:.0}/{:.0}/{:.0}/{:.0})",
+                attacker, if *crit { "crits" } else { "hits" }, target, damage.sum(), damage.bleed, damage.fracture, damage.madness, damage.void
+            ),
+            Blocked { attacker, target } => write!(f, "{} blocks the attack of {}", target, attacker),
+            Evaded { attacker, target } => write!(f, "{} evades the attack of {}", target, attacker),
+            Culled { attacker, target } => write!(f, "{} culls {}", attacker, target),
+            Healed { source, target, amount } if source == target => write!(f, "{} heals for {:.0}", target, amount),
+            Healed { source, target, amount } => write!(f, "{} heals {} for {:.0}", source, target, amount),
+            Shielded { source, target, amount } if source == target => write!(f, "{} shields for {:.0}", target, amount),
+            Shielded { source, target, amount } => write!(f, "{} shields {} for {:.0}", source, target, amount),
+            DotTick { target, debuff, damage, .. } => write!(f, "{} takes {:.1} damage from {:?}", target, damage, debuff),
+            BuffApplied { target, buff } => write!(f, "{} gains {:?}", target, buff),
+            ShieldBroken { target } => write!(f, "{}'s shield breaks", target),
+            Died { target } => write!(f, "{} dies", target),
+        }
+    }
+}
+
+// shared by everyone in a battle, so the events keep the order they happened in
+#[derive(Debug, Clone, Default)]
+pub struct EventSink(Rc<RefCell<Vec<CombatEvent>>>);
+
+impl EventSink {
+    pub fn push(&self, event: CombatEvent) {
+        self.0.borrow_mut().push(event);
+    }
+    pub fn take(&self) -> Vec<CombatEvent> {
+        mem::take(&mut self.0.borrow_mut())
+    }
+    // clone only shares the sink, this is a copy of it that goes its own way
+    pub fn detached(&self) -> Self {
+        Self(Rc::new(RefCell::new(self.0.borrow().clone())))
+    }
+}
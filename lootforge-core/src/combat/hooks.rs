@@ -6,28 +6,37 @@ use crate::combat::{
     },
 };
 
-#[derive(Default)]
+#[derive(Clone, Default)]
 pub struct CombatHooks {
     pre_attack: Vec<Box<dyn PreAttackHook>>,
-    pre_hit: Vec<Box<dyn PreHitHook>>,
-    post_hit: Vec<Box<dyn PostHitHook>>,
+    // tagged with the mod that registered them, so the debuffs they inflict can be traced back to it
+    pre_hit: Vec<(Option<u16>, Box<dyn PreHitHook>)>,
+    post_hit: Vec<(Option<u16>, Box<dyn PostHitHook>)>,
     resp_pre_atk: Vec<Box<dyn PreAttackRespHook>>,
     resp_post_atk: Vec<Box<dyn PostAttackRespHook>>,
     defend: Vec<Box<dyn DefHook>>,
     char: Vec<Box<dyn CharHook>>,
     combat_start: Vec<Box<dyn CombatStartHook>>,
+    registering: Option<u16>,
     // evtl die buff only hooks <- think about how buffs play into this
 }
 
 impl CombatHooks {
+    // hooks added inside of register belong to the mod
+    pub fn register_mod(&mut self, mod_id: u16, register: impl FnOnce(&mut Self)) {
+        self.registering = Some(mod_id);
+        register(self);
+        self.registering = None;
+    }
+
     pub fn on_pre_attack(&mut self, hook: impl PreAttackHook) {
         self.pre_attack.push(Box::new(hook));
     }
     pub fn on_pre_hit(&mut self, hook: impl PreHitHook) {
-        self.pre_hit.push(Box::new(hook));
+        self.pre_hit.push((self.registering, Box::new(hook)));
     }
     pub fn on_post_hit(&mut self, hook: impl PostHitHook) {
-        self.post_hit.push(Box::new(hook));
+        self.post_hit.push((self.registering, Box::new(hook)));
     }
     pub fn on_resp_pre_atk(&mut self, hook: impl PreAttackRespHook) {
         self.resp_pre_atk.push(Box::new(hook));
@@ -49,10 +58,16 @@ impl CombatHooks {
         self.pre_attack.iter().for_each(|hook| hook(attack, skill, user, targets));
     }
     pub fn pre_hit(&self, attack: &mut PreHit, skill: &Skill, user: &Combatant, target: &Combatant) {
-        self.pre_hit.iter().for_each(|hook| hook(attack, skill, user, target));
+        for (mod_id, hook) in &self.pre_hit {
+            hook(attack, skill, user, target);
+            attack.debuff_mods.resize(attack.debuffs.len(), *mod_id);
+        }
     }
     pub fn post_hit(&self, attack: &mut PostHit, skill: &Skill, user: &Combatant, target: &Combatant, hit: &Hit) {
-        self.post_hit.iter().for_each(|hook| hook(attack, skill, user, target, hit));
+        for (mod_id, hook) in &self.post_hit {
+            hook(attack, skill, user, target, hit);
+            attack.debuff_mods.resize(attack.debuffs.len(), *mod_id);
+        }
     }
     pub fn resp_pre_atk(&self, resp: &mut ResponsePreHit, skill: &Skill, user: &Combatant, attacker: &Combatant) {
         self.resp_pre_atk.iter().for_each(|hook| hook(resp, skill, user, attacker));
@@ -83,20 +98,31 @@ impl std::fmt::Debug for CombatHooks {
             .finish()
     }
 }
-pub trait PreAttackHook: Fn(&mut PreAttack, &Skill, &Combatant, &Vec<&Combatant>) + 'static {}
-pub trait PreHitHook: Fn(&mut PreHit, &Skill, &Combatant, &Combatant) + 'static {}
-pub trait PostHitHook: Fn(&mut PostHit, &Skill, &Combatant, &Combatant, &Hit) + 'static {}
-pub trait PreAttackRespHook: Fn(&mut ResponsePreHit, &Skill, &Combatant, &Combatant) + 'static {}
-pub trait PostAttackRespHook: Fn(&mut ResponsePostHit, &Skill, &Combatant, &Combatant, &Hit) + 'static {}
-pub trait DefHook: Fn(&mut Defend, &Skill, &Combatant) + 'static {}
-pub trait CharHook: Fn(&mut CharStats) + 'static {}
-pub trait CombatStartHook: Fn(&mut CombatStartEffects, &Combatant) + 'static {}
+// hooks are cloned with the combatants that carry them, so a dungeon can be copied mid run
+macro_rules! hook_trait {
+    ($name:ident: Fn($($arg:ty),*)) => {
+        pub trait $name: Fn($($arg),*) + 'static {
+            fn clone_box(&self) -> Box<dyn $name>;
+        }
+        impl<T: Fn($($arg),*) + Clone + 'static> $name for T {
+            fn clone_box(&self) -> Box<dyn $name> {
+                Box::new(self.clone())
+            }
+        }
+        // the box is a hook itself, so the inner one has to be asked or this calls itself forever
+        impl Clone for Box<dyn $name> {
+            fn clone(&self) -> Self {
+                (**self).clone_box()
+            }
+        }
+    };
+}
 
-impl<T: Fn(&mut PreAttack, &Skill, &Combatant, &Vec<&Combatant>) + 'static> PreAttackHook for T {}
-impl<T: Fn(&mut PreHit, &Skill, &Combatant, &Combatant) + 'static> PreHitHook for T {}
-impl<T: Fn(&mut PostHit, &Skill, &Combatant, &Combatant, &Hit) + 'static> PostHitHook for T {}
-impl<T: Fn(&mut ResponsePreHit, &Skill, &Combatant, &Combatant) + 'static> PreAttackRespHook for T {}
-impl<T: Fn(&mut ResponsePostHit, &Skill, &Combatant, &Combatant, &Hit) + 'static> PostAttackRespHook for T {}
-impl<T: Fn(&mut Defend, &Skill, &Combatant) + 'static> DefHook for T {}
-impl<T: Fn(&mut CharStats) + 'static> CharHook for T {}
-impl<T: Fn(&mut CombatStartEffects, &Combatant) + 'static> CombatStartHook for T {}
+hook_trait!(PreAttackHook: Fn(&mut PreAttack, &Skill, &Combatant, &Vec<&Combatant>));
+hook_trait!(PreHitHook: Fn(&mut PreHit, &Skill, &Combatant, &Combatant));
+hook_trait!(PostHitHook: Fn(&mut PostHit, &Skill, &Combatant, &Combatant, &Hit));
+hook_trait!(PreAttackRespHook: Fn(&mut ResponsePreHit, &Skill, &Combatant, &Combatant));
+hook_trait!(PostAttackRespHook: Fn(&mut ResponsePostHit, &Skill, &Combatant, &Combatant, &Hit));
+hook_trait!(DefHook: Fn(&mut Defend, &Skill, &Combatant));
+hook_trait!(CharHook: Fn(&mut CharStats));
+hook_trait!(CombatStartHook: Fn(&mut CombatStartEffects, &Combatant));
@@ -2,5 +2,8 @@ pub mod battle;
 pub mod buff;
 pub mod combatant;
 pub mod enemy;
+pub mod event;
 pub mod hooks;
-pub mod skill;
\ No newline at end of file
+pub mod rng;
+pub mod skill;
+pub mod threat;
\ No newline at end of file
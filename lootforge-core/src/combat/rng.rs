@@ -0,0 +1,31 @@
+use rand_chacha::ChaCha12Rng;
+
+use crate::prelude::*;
+
+// shared by everyone in a battle like the EventSink, seeded from the dungeon rng so a replay rolls the same
+#[derive(Debug, Clone)]
+pub struct CombatRng(Rc<RefCell<ChaCha12Rng>>);
+
+impl Default for CombatRng {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl CombatRng {
+    pub fn new(seed: u64) -> Self {
+        Self(Rc::new(RefCell::new(ChaCha12Rng::seed_from_u64(seed))))
+    }
+    // clone only shares the rng, this is a copy of it that rolls on its own
+    pub fn detached(&self) -> Self {
+        Self(Rc::new(RefCell::new(self.0.borrow().clone())))
+    }
+
+    // nothing is rolled without a chance, so combat without chance effects doesn't touch the rng
+    pub fn chance(&self, chance: f32) -> bool {
+        if chance <= 0. {
+            return false;
+        }
+        self.0.borrow_mut().random::<f32>() < chance
+    }
+}
@@ -14,10 +14,10 @@ pub struct PreAttack {
 pub fn attack_single(
     skill: &mut Skill,
     user: &mut Combatant,
-    _allies: &mut Vec<&mut Combatant>,
-    enemies: &mut Vec<&mut Combatant>,
+    allies: &mut [&mut Combatant],
+    enemies: &mut [&mut Combatant],
 ) -> AttackStats {
-    let target = &mut skill.targeting.select_target(enemies);
+    let target = &mut skill.targeting.select_target(enemies, allies);
     attack_target(skill, user, target)
 }
 
@@ -26,6 +26,7 @@ pub fn attack_target(
     user: &mut Combatant,
     target: &mut Combatant,
 ) -> AttackStats {
+    user.last_target = Some(target.kind);
     let mut attack = PreAttack::default();
     let targets: Vec<&Combatant> = vec![target];
     skill.hooks.pre_attack(&mut attack, skill, user, &targets);
@@ -46,8 +47,8 @@ pub fn attack_target(
 pub fn attack_aoe(
     skill: &mut Skill,
     user: &mut Combatant,
-    _allies: &mut Vec<&mut Combatant>,
-    enemies: &mut Vec<&mut Combatant>,
+    _allies: &mut [&mut Combatant],
+    enemies: &mut [&mut Combatant],
 ) -> AttackStats {
     let mut attack = PreAttack::default();
     let targets: Vec<&Combatant> = enemies.iter().map(|c| c as &Combatant).collect();
@@ -1,5 +1,5 @@
 use crate::prelude::*;
-use crate::combat::{buff::Buff, combatant::{Combatant, CombatantKind}};
+use crate::combat::{buff::Buff, combatant::{Combatant, CombatantKind}, event::CombatEvent};
 
 use super::skill::Skill;
 
@@ -16,21 +16,28 @@ pub fn defend(
 pub struct Defend {
     pub shield: f32,
     pub heal: f32,
+    pub taunt: f32,
     pub buffs: Vec<Buff>,
 }
 
 fn def(skill: &Skill, user: &mut Combatant) -> DefStats {
     let mut defend = Defend::default();
     skill.hooks.defend(&mut defend, skill, user);
-    user.hooks.defend(&mut defend, &skill, user);
+    user.hooks.defend(&mut defend, skill, user);
     user.buffs.apply_to_def(&mut defend, skill, user);
 
-    defend.buffs.into_iter().for_each(|b| user.buffs.add(b));
+    defend.buffs.into_iter().for_each(|b| user.add_buff(b));
 
     let shielded = user.shield(defend.shield);
     let healed = user.heal(defend.heal);
-
-    DefStats { defender: user.kind, healed, shielded }
+    if healed > 0. {
+        user.events.push(CombatEvent::Healed { source: user.kind, target: user.kind, amount: healed });
+    }
+    if shielded > 0. {
+        user.events.push(CombatEvent::Shielded { source: user.kind, target: user.kind, amount: shielded });
+    }
+
+    DefStats { defender: user.kind, healed, shielded, taunted: defend.taunt }
 }
 
 #[derive(Debug, Clone)]
@@ -38,4 +45,5 @@ pub struct DefStats {
     pub defender: CombatantKind,
     pub healed: f32,
     pub shielded: f32,
+    pub taunted: f32,
 }
\ No newline at end of file
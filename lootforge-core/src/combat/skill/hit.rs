@@ -1,9 +1,11 @@
-use crate::combat::buff::{Buff, Debuff};
+use crate::combat::buff::{Buff, BuffOrigin, Debuff};
 use crate::combat::skill::attack::AttackStats;
-use crate::combat::skill::skill::SkillStats;
+use crate::combat::skill::skill::{SkillSource, SkillStats};
 use crate::elemental::Element;
 use crate::prelude::*;
 use crate::combat::combatant::CombatantKind;
+use crate::combat::event::CombatEvent;
+use crate::combat::rng::CombatRng;
 
 use crate::{combat::{combatant::Combatant}, elemental::Elemental};
 
@@ -15,22 +17,34 @@ pub struct Hit {
     pub post_res_dmg: Elemental<f32>,
     pub penetration: Elemental<f32>,
     pub ignore_res: Elemental<bool>,
+    pub crit: bool,
+    pub evaded: bool,
 }
 
 #[apply(Default)]
 pub struct PreHit {
+    // for hooks that want to roll something themselves
+    pub rng: CombatRng,
     pub damage: Elemental<f32>,
     #[default(Elemental::from(1.))]
     pub damage_mult: Elemental<f32>,
+    pub crit_chance: f32,
+    #[default(1.)]
+    pub crit_multi: f32,
     pub penetration: Elemental<f32>,
     pub pen_conversion: Elemental<bool>,
     pub ignore_res: Elemental<bool>,
     pub debuffs: Vec<Debuff>,
+    // the mod behind each debuff, filled in by CombatHooks
+    pub debuff_mods: Vec<Option<u16>>,
 }
 
 #[apply(Default)]
 pub struct PostHit {
+    pub rng: CombatRng,
     pub debuffs: Vec<Debuff>,
+    // the mod behind each debuff, filled in by CombatHooks
+    pub debuff_mods: Vec<Option<u16>>,
     pub cull_threshhold: f32,
     pub life_steal: f32,
     pub shield_steal: f32,
@@ -51,36 +65,47 @@ pub struct ResponsePostHit {
 
 pub fn hit(skill: &Skill, user: &mut Combatant, target: &mut Combatant) -> HitStats {
     let (hit, response) = pre_hit(skill, user, target);
-    if let Some(hit) = hit {
-        post_hit(skill, user, target, hit, response)
-    } else {
-        HitStats { target: target.kind, hit: Hit::default(), responses: response.into_iter().collect() }
+    match hit {
+        Ok(hit) => post_hit(skill, user, target, hit, response),
+        // blocked or evaded
+        Err(missed) => HitStats { target: target.kind, hit: missed, responses: response.into_iter().collect() },
     }
 }
 
 
-fn pre_hit(skill: &Skill, user: &mut Combatant, target: &mut Combatant) -> (Option<Hit>, Option<SkillStats>) {
-    let mut attack = PreHit::default();
+fn pre_hit(skill: &Skill, user: &mut Combatant, target: &mut Combatant) -> (Result<Hit, Hit>, Option<SkillStats>) {
+    let user_stats = user.stats();
+    let mut attack = PreHit { rng: user.rng.clone(), crit_chance: user_stats.crit_chance, crit_multi: user_stats.crit_multi, ..Default::default() };
     skill.hooks.pre_hit(&mut attack, skill, user, target);
     user.hooks.pre_hit(&mut attack, skill, user, target);
     user.buffs.apply_pre_hit(&mut attack, skill, user, target);
     target.buffs.apply_pre_getting_hit(&mut attack);
 
     let (response, response_stats) = target.trigger_skill_against_attack(user, true).split();
-    if let Some(response) = response {
-        response.debuffs.into_iter().for_each(|b| user.buffs.add(b));
+    if let (Some(response), Some(stats)) = (response, &response_stats) {
+        inflict(user, response.debuffs, Vec::new(), target.kind, stats.source());
 
         if response.block {
+            user.events.push(CombatEvent::Blocked { attacker: user.kind, target: target.kind });
             // make sure all other effects that happen later are exclusive with block
-            return (None, response_stats);
+            return (Err(Hit::default()), response_stats);
         }
     }
 
+    if attack.rng.chance(target.stats().evasion) {
+        user.events.push(CombatEvent::Evaded { attacker: user.kind, target: target.kind });
+        return (Err(Hit { evaded: true, ..Default::default() }), response_stats);
+    }
+
     // trigger attack pre hit effects
-    attack.debuffs.into_iter().for_each(|b| target.buffs.add(b));
+    inflict(target, attack.debuffs, attack.debuff_mods, user.kind, &skill.source);
 
     // hit
-    let pre_res_dmg = attack.damage * attack.damage_mult;
+    let crit = attack.rng.chance(attack.crit_chance);
+    let mut pre_res_dmg = attack.damage * attack.damage_mult;
+    if crit {
+        pre_res_dmg = pre_res_dmg * attack.crit_multi;
+    }
 
     let target_stats = target.stats();
     let penetration = penetration(attack.penetration, attack.pen_conversion);
@@ -88,14 +113,16 @@ fn pre_hit(skill: &Skill, user: &mut Combatant, target: &mut Combatant) -> (Opti
     let mut post_res_dmg = mitigation(pre_res_dmg, effective_res);
     post_res_dmg.assign_cond(pre_res_dmg, attack.ignore_res);
 
+    user.events.push(CombatEvent::Hit { attacker: user.kind, target: target.kind, damage: post_res_dmg, absorbed: target.absorbs(post_res_dmg.sum()), crit });
     target.damage(post_res_dmg.sum());
-    let hit = Hit { pre_res_dmg, post_res_dmg, penetration, ignore_res: attack.ignore_res };
+    user.recent_damage += post_res_dmg.sum();
+    let hit = Hit { pre_res_dmg, post_res_dmg, penetration, ignore_res: attack.ignore_res, crit, evaded: false };
 
-    ( Some(hit), response_stats )
+    ( Ok(hit), response_stats )
 }
 
 fn post_hit(skill: &Skill, user: &mut Combatant, target: &mut Combatant, hit: Hit, response: Option<SkillStats>) -> HitStats {
-    let mut attack = PostHit::default();
+    let mut attack = PostHit { rng: user.rng.clone(), ..Default::default() };
     skill.hooks.post_hit(&mut attack, skill, user, target, &hit);
     user.hooks.post_hit(&mut attack, skill, user, target, &hit);
     user.buffs.apply_post_hit(&mut attack, skill, user, target, &hit);
@@ -111,9 +138,10 @@ fn post_hit(skill: &Skill, user: &mut Combatant, target: &mut Combatant, hit: Hi
         let mut post_res_dmg = mitigation(hit.pre_res_dmg, effective_res);
         post_res_dmg.assign_cond(hit.pre_res_dmg, hit.ignore_res);
 
+        user.events.push(CombatEvent::Hit { attacker: user.kind, target: user.kind, damage: post_res_dmg, absorbed: user.absorbs(post_res_dmg.sum()), crit: hit.crit });
         user.damage(post_res_dmg.sum());
         // TODO maybe introduce a shorter way to do this?
-        let self_hit = Hit { pre_res_dmg: hit.pre_res_dmg, post_res_dmg, penetration: hit.penetration, ignore_res: hit.ignore_res };
+        let self_hit = Hit { pre_res_dmg: hit.pre_res_dmg, post_res_dmg, penetration: hit.penetration, ignore_res: hit.ignore_res, crit: hit.crit, evaded: false };
         let self_hit_stats = HitStats { target: user.kind, hit: self_hit, responses: Vec::new() };
         let self_attack_stats = AttackStats { attacker: user.kind, hits: vec![self_hit_stats] };
         responses.push( SkillStats::Attack( skill.source.clone(), self_attack_stats) );
@@ -121,38 +149,56 @@ fn post_hit(skill: &Skill, user: &mut Combatant, target: &mut Combatant, hit: Hi
 
     let dmg = hit.post_res_dmg.sum();
     if attack.life_steal > 0. {
-        user.heal(dmg * attack.life_steal);
+        let healed = user.heal(dmg * attack.life_steal);
+        if healed > 0. {
+            user.events.push(CombatEvent::Healed { source: user.kind, target: user.kind, amount: healed });
+        }
     }
     if attack.shield_steal > 0. {
-        user.shield(dmg * attack.shield_steal);
+        let shielded = user.shield(dmg * attack.shield_steal);
+        if shielded > 0. {
+            user.events.push(CombatEvent::Shielded { source: user.kind, target: user.kind, amount: shielded });
+        }
     }
 
-    attack.debuffs.into_iter().for_each(|b| target.buffs.add(b));
+    inflict(target, attack.debuffs, attack.debuff_mods, user.kind, &skill.source);
 
     let percent_health = target.health / target_stats.max_health;
     if percent_health < attack.cull_threshhold / 100. {
+        user.events.push(CombatEvent::Culled { attacker: user.kind, target: target.kind });
         target.health = 0.;
     }
 
-    // TODO somewhere (prolly not here) do taunt calculation
+    target.threat.add(user.kind, dmg);
 
     if let Some(stats) = &response {
         let resp = target.trigger_post_attack(stats.source(), user, &hit);
 
         if resp.counter {
             let counter = target.trigger_attack_against_target(user, false);
-            counter.map(|c| responses.push(c));
+            if let Some(c) = counter {
+                responses.push(c);
+            }
         }
 
-        resp.buffs.into_iter().for_each(|b| target.buffs.add(b));
-        resp.debuffs.into_iter().for_each(|b| user.buffs.add(b));
-
+        resp.buffs.into_iter().for_each(|b| target.add_buff(b));
+        inflict(user, resp.debuffs, Vec::new(), target.kind, stats.source());
     }
 
-    response.map(|r| responses.insert(0, r));
+    if let Some(r) = response {
+        responses.insert(0, r);
+    }
     HitStats { target: target.kind, hit, responses }
 }
 
+// debuffs without a mod came from buffs of the attacker
+fn inflict(target: &mut Combatant, debuffs: Vec<Debuff>, mods: Vec<Option<u16>>, applier: CombatantKind, source: &SkillSource) {
+    let mods = mods.into_iter().chain(std::iter::repeat(None));
+    debuffs.into_iter()
+        .zip(mods)
+        .for_each(|(debuff, mod_id)| target.inflict(debuff, BuffOrigin { applier, source: source.clone(), mod_id }));
+}
+
 pub fn effective_health<F: Copy + ops::Mul<f32, Output=F> + ops::Mul<Output=F> + ops::Div<Output=F> + ops::Sub<Output=F> + NumExt + From<f32>>(health: F, res: F) -> F {
     let factor = F::from(1.) / damage_reduced_by(res);
     health * factor
@@ -168,7 +214,7 @@ fn damage_reduced_by<F: Copy + ops::Mul<f32, Output=F> + ops::Sub<Output=F> + Nu
 }
 
 pub fn penetration(penetration: Elemental<f32>, pen_conversion: Elemental<bool>) -> Elemental<f32> {
-    let mut total_pen = penetration.clone();
+    let mut total_pen = penetration;
     Element::iter().for_each(|element| {
         if *pen_conversion.get(element) {
             total_pen = total_pen + *penetration.get(element);
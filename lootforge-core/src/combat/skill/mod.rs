@@ -2,4 +2,5 @@ pub mod attack;
 pub mod defend;
 pub mod hit;
 pub mod skill;
+pub mod support;
 pub mod targeting;
\ No newline at end of file
@@ -1,20 +1,23 @@
 use std::rc::Rc;
 
 use crate::combat::combatant::{CharStats, Combatant};
-use crate::combat::skill::{attack, defend};
+use crate::combat::skill::{attack, defend, support};
 use crate::equipment::equipment::{Equip, EquipEnum};
 use crate::{item::item::Item, item::item_type::ItemType};
 use crate::combat::hooks::CombatHooks;
+use crate::combat::{buff::DotStats, threat};
 use crate::prelude::*;
 
-use super::{attack::AttackStats, defend::DefStats, targeting::Targeting};
+use super::{attack::AttackStats, defend::DefStats, support::SupportStats, targeting::Targeting};
 
 use SkillKind::*;
 
-#[derive(derive_more::Debug)]
+#[derive(Clone, derive_more::Debug)]
 pub struct Skill {
     pub source: SkillSource,
     pub targeting: Targeting,
+    // percent of max health the user has to be below for the skill to fire, who it hits is still up to targeting
+    pub health_gate: Option<u8>,
     #[debug(skip)]
     pub hooks: CombatHooks,
     pub cd: u16,
@@ -37,7 +40,8 @@ impl Skill { // constructors
             let mut res = Self {
                 source: SkillSource::Item { id: item.id, item_type: item.item_type },
                 targeting,
-                hooks: hooks,
+                health_gate: None,
+                hooks,
                 cd: 0,
                 uses: 0,
             };
@@ -53,12 +57,18 @@ impl Skill { // constructors
 
         Self {
             source: SkillSource::Enemy { kind, cd },
-            targeting: Targeting::First, // TODO aggro
+            targeting: Targeting::First, // the party is sorted by threat before enemies act
+            health_gate: None,
             hooks,
             cd,
             uses: 0,
         }
     }
+    // the skill only fires while the user is below the given percent of health
+    pub fn below_health(mut self, percent: u8) -> Self {
+        self.health_gate = Some(percent);
+        self
+    }
 }
 
 impl Skill {
@@ -74,14 +84,7 @@ impl Skill {
         };
 
         // TODO consider a different hook for cdr...
-        let mut char = CharStats {
-            max_health: 0.,
-            resistances: Default::default(),
-            heal_power: 1.,
-            shield_power: 1.,
-            cdr: 0,
-            tick_rate: 1,
-        };
+        let mut char = CharStats::base();
         // cdr can currently only roll on the skill giving items, so we only nee to check our mods
         self.hooks.char(&mut char);
 
@@ -89,17 +92,30 @@ impl Skill {
         base - reduced_ticks
     }
 
-    pub fn image(&self) -> Image<'_> {        
-        match &self.source {
-            SkillSource::Item { item_type, .. } => item_type.image(),
-            SkillSource::Enemy { .. } => panic!("enemy skills do not have images"),
-        }
-    }
-
     pub fn ready(&self) -> bool {
         self.cd == 0
     }
 
+    // whether a ready skill may fire on its own, OnAttack skills only fire as a response
+    pub fn triggers(&self, user: &Combatant, allies: &[&mut Combatant]) -> bool {
+        let below = |c: &Combatant, percent: u8| c.health < c.stats().max_health * percent as f32 / 100.;
+        if self.health_gate.is_some_and(|percent| !below(user, percent)) {
+            return false;
+        }
+        match self.targeting {
+            Targeting::OnAttack => false,
+            Targeting::BelowHealth(percent) => {
+                // support skills watch the allies they would help, everything else only its user
+                if self.kind().is_support() && !allies.is_empty() {
+                    allies.iter().any(|a| below(a, percent))
+                } else {
+                    below(user, percent)
+                }
+            },
+            _ => true,
+        }
+    }
+
     pub fn tick(&mut self) {
         if self.cd > 0 {
             self.cd -= 1;
@@ -109,16 +125,18 @@ impl Skill {
     pub fn trigger<'a, 'b>(
         &mut self,
         user: &mut Combatant,
-        allies: &'a mut Vec<&'b mut Combatant>,
-        enemies: &'a mut Vec<&'b mut Combatant>,
+        allies: &'a mut [&'b mut Combatant],
+        enemies: &'a mut [&'b mut Combatant],
         reset_cooldown: bool,
-    ) -> SkillStats {        
+    ) -> SkillStats {
         use SkillKind::*;
         let skill_stats = match self.kind() {
             Attack =>    SkillStats::Attack(self.source.clone(), attack::attack_single(self, user, allies, enemies)),
             AoeAttack => SkillStats::Attack(self.source.clone(), attack::attack_aoe(self, user, allies, enemies)),
-            Defend =>    todo!(), // TODO
+            Defend =>    SkillStats::Defend(self.source.clone(), defend::defend(self, user)),
+            HealAlly | ShieldAlly | BuffAlly => SkillStats::Support(self.source.clone(), support::support(self, user, allies)),
         };
+        threat::generate(&skill_stats, enemies.iter_mut().map(|e| &mut **e));
         if reset_cooldown {
             self.cd = self.cooldown();
         }
@@ -134,7 +152,10 @@ impl Skill {
         let skill_stats = match self.kind() {
             Attack | AoeAttack => SkillStats::Attack(self.source.clone(), attack::attack_target(self, user, target)),
             Defend => SkillStats::Defend(self.source.clone(), defend::defend(self, user)),
+            // there is no ally to pick when reacting to an attack, so the user gets the support
+            HealAlly | ShieldAlly | BuffAlly => SkillStats::Support(self.source.clone(), support::support(self, user, &mut [])),
         };
+        threat::generate(&skill_stats, once(target));
         if reset_cooldown {
             self.cd = self.cooldown();
         }
@@ -148,24 +169,35 @@ pub enum SkillKind {
     Attack ,
     AoeAttack,
     Defend,
-    // the 3 support skills
+    HealAlly,
+    ShieldAlly,
+    BuffAlly,
 }
 impl SkillKind {
     pub fn from_item_type(item_type: ItemType) -> Option<Self> {
         use ItemType::*;
         match item_type {
-            Axe | Crossbow => Some(Attack), // mage gem
-            Sword | Bow => Some(AoeAttack), // mage gem
-            // 3 support skills
+            Axe | Crossbow | AttackGem => Some(Attack),
+            Sword | Bow | AoeGem => Some(AoeAttack),
+            Satchel => Some(HealAlly),
+            SupportGem => Some(ShieldAlly),
+            Staff => Some(BuffAlly),
             Helmet => Some(Defend),
-            _ => return None,
+            _ => None,
         }
     }
 
+    pub fn is_support(&self) -> bool {
+        matches!(self, HealAlly | ShieldAlly | BuffAlly)
+    }
+
     pub fn default_targeting(&self) -> Targeting {
         match self {
             SkillKind::Attack | AoeAttack => Targeting::First,
             Defend => Targeting::OnAttack,
+            HealAlly => Targeting::LowestHealth,
+            ShieldAlly => Targeting::First,
+            BuffAlly => Targeting::HighestDamage,
         }
     }
 
@@ -174,6 +206,9 @@ impl SkillKind {
             SkillKind::Attack => 20,
             AoeAttack         => 30,
             SkillKind::Defend => 70,
+            HealAlly          => 60,
+            ShieldAlly        => 50,
+            BuffAlly          => 40,
         }
     }
 }
@@ -197,12 +232,17 @@ impl SkillSource {
 pub enum SkillStats {
     Attack(SkillSource, AttackStats),
     Defend(SkillSource, DefStats),
+    Support(SkillSource, SupportStats),
+    // a tick of damage over time, the source is the skill that inflicted the debuff
+    Dot(SkillSource, DotStats),
 }
 impl SkillStats {
     pub fn source(&self) -> &SkillSource {
         match self {
             SkillStats::Attack(skill_source, _) => skill_source,
             SkillStats::Defend(skill_source, _) => skill_source,
+            SkillStats::Support(skill_source, _) => skill_source,
+            SkillStats::Dot(skill_source, _) => skill_source,
         }
     }
 }
\ No newline at end of file
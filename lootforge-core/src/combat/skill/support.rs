@@ -0,0 +1,61 @@
+use crate::prelude::*;
+use crate::combat::{buff::Buff, combatant::{Combatant, CombatantKind}, event::CombatEvent};
+
+use super::{defend::Defend, skill::{Skill, SkillKind}};
+
+pub fn support(
+    skill: &mut Skill,
+    user: &mut Combatant,
+    allies: &mut [&mut Combatant],
+) -> SupportStats {
+    let stats = sup(skill, user, allies);
+    skill.uses += 1;
+    stats
+}
+
+fn sup(skill: &mut Skill, user: &mut Combatant, allies: &mut [&mut Combatant]) -> SupportStats {
+    // support skills roll the same mods as defensive skills, they just hand the result to someone else
+    let mut support = base_support(skill.kind());
+    skill.hooks.defend(&mut support, skill, user);
+    user.hooks.defend(&mut support, skill, user);
+    user.buffs.apply_to_def(&mut support, skill, user);
+
+    let supporter = user.kind;
+    // with no ally left standing, the skill falls back to its user
+    let target = if allies.is_empty() {
+        user
+    } else {
+        skill.targeting.select_target(allies, &[])
+    };
+
+    support.buffs.into_iter().for_each(|b| target.add_buff(b));
+
+    let shielded = target.shield(support.shield);
+    let healed = target.heal(support.heal);
+    if healed > 0. {
+        target.events.push(CombatEvent::Healed { source: supporter, target: target.kind, amount: healed });
+    }
+    if shielded > 0. {
+        target.events.push(CombatEvent::Shielded { source: supporter, target: target.kind, amount: shielded });
+    }
+
+    SupportStats { supporter, target: target.kind, healed, shielded }
+}
+
+// each kind does its own thing even without mods, the mods add on top
+fn base_support(kind: SkillKind) -> Defend {
+    match kind {
+        SkillKind::HealAlly => Defend { heal: 10., ..Default::default() },
+        SkillKind::ShieldAlly => Defend { shield: 15., ..Default::default() },
+        SkillKind::BuffAlly => Defend { buffs: vec![Buff::empowered(10.)], ..Default::default() },
+        _ => Defend::default(),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SupportStats {
+    pub supporter: CombatantKind,
+    pub target: CombatantKind,
+    pub healed: f32,
+    pub shielded: f32,
+}
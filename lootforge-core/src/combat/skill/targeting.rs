@@ -0,0 +1,106 @@
+use crate::combat::buff::BuffIcon;
+use crate::combat::combatant::CombatantKind;
+use crate::combat::enemy::EnemyType;
+use crate::prelude::*;
+
+use crate::{combat::combatant::Combatant, elemental::Element};
+
+use Targeting::*;
+
+#[apply(Enum)]
+#[derive(Copy, PartialEq)]
+pub enum Targeting {
+    // default
+    First,
+
+    // Rings
+    LowestHealth,
+    LowestResistance(Element),
+    HighestMaxHealth,
+    HighestDamage,
+    RoundRobin(u8),
+    MostDebuffs,
+    MissingDebuff(BuffIcon),
+    // whatever the allies attacked last
+    FocusAlly,
+    LowestShield,
+    // Fighter, Range, Mage (for support skills once we have those chars in)
+
+    // triggering for defense skills
+    Instant,
+    OnAttack,
+    BelowHealth(u8), // percent of max health
+}
+impl Targeting {
+    // a trigger mode on a weapon makes it fire whenever ready, only as a counter or only while its user is low
+    pub fn roll_ring(rng: &mut impl Rng) -> Self {
+        match rng.random_range(0..=11) {
+            0 => LowestHealth,
+            1 => LowestResistance(*Element::VARIANTS.pick(rng)),
+            2 => HighestMaxHealth,
+            3 => HighestDamage,
+            4 => RoundRobin(0),
+            5 => Instant,
+            6 => OnAttack,
+            7 => Self::roll_below_health(rng),
+            8 => MostDebuffs,
+            9 => MissingDebuff(*BuffIcon::DEBUFFS.pick(rng)),
+            10 => FocusAlly,
+            11 => LowestShield,
+            _ => panic!(),
+        }
+    }
+    pub fn roll_trigger(rng: &mut impl Rng) -> Self {
+        match rng.random_range(0..=2) {
+            0 => Instant,
+            1 => OnAttack,
+            2 => Self::roll_below_health(rng),
+            _ => panic!(),
+        }
+    }
+    fn roll_below_health(rng: &mut impl Rng) -> Self {
+        BelowHealth(rng.random_range(3..=7) * 10)
+    }
+
+    // allies are only needed to follow their lead, support skills pick among the allies themselves
+    pub fn select_target<'a>(&mut self, targets: &'a mut [&mut Combatant], allies: &[&mut Combatant]) -> &'a mut Combatant {
+        match self {
+            First => targets[0],
+            LowestHealth => targets.iter_mut().min_by_key(|t| F32Ord(t.health)).unwrap(),
+            LowestResistance(element) => targets.iter_mut().min_by_key(|t| F32Ord(*t.stats().resistances.get(*element))).unwrap(),
+            HighestMaxHealth => targets.iter_mut().max_by_key(|t| F32Ord(t.stats().max_health)).unwrap(),
+            HighestDamage => {
+                // before anyone has dealt damage, guess from what they are
+                targets.iter_mut().max_by_key(|t| (F32Ord(t.recent_damage), match t.kind {
+                    // support skills pick among allies, whoever brings the most attacks
+                    CombatantKind::Fighter | CombatantKind::Ranger | CombatantKind::Mage => t.skills.iter().filter(|s| s.kind().is_attack()).count(),
+                    CombatantKind::Enemy(_, enemy_kind) => match enemy_kind.etype() {
+                        EnemyType::Small => 1,
+                        EnemyType::Medium => 3,
+                        EnemyType::Tank => 2,
+                        EnemyType::Dps => 4,
+                        EnemyType::Boss => 5,
+                    },
+                })).unwrap()
+            },
+            RoundRobin(i) => {
+                let len = targets.len();
+                *i = i.wrapping_add(1); targets[*i as usize % len]
+            },
+            MostDebuffs => targets.iter_mut().max_by_key(|t| t.buffs.debuff_count()).unwrap(),
+            MissingDebuff(icon) => match targets.iter().position(|t| !t.buffs.has(*icon)) {
+                Some(i) => targets[i],
+                None => targets[0],
+            },
+            FocusAlly => {
+                let focus = |t: &Combatant| allies.iter().filter(|a| a.last_target == Some(t.kind)).count();
+                // min_by_key keeps the first of equals, so without a lead this is the same as First
+                targets.iter_mut().min_by_key(|t| std::cmp::Reverse(focus(t))).unwrap()
+            },
+            LowestShield => targets.iter_mut().min_by_key(|t| F32Ord(t.shield)).unwrap(),
+            // trigger modes bring no real targeting, so they go for the first or the one in danger
+            Instant | OnAttack => targets[0],
+            BelowHealth(_) => targets.iter_mut().min_by_key(|t| F32Ord(t.health)).unwrap(),
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use std::cmp::Reverse;
+
+use crate::prelude::*;
+
+use super::{combatant::{Combatant, CombatantKind}, skill::skill::SkillStats};
+
+// healing and shielding draw less attention than hitting something
+const SUPPORT_THREAT: f32 = 0.5;
+
+// how much each explorer annoyed an enemy, only enemies keep track of it
+#[derive(Debug, Clone, Default)]
+pub struct ThreatTable {
+    fighter: f32,
+    ranger: f32,
+    mage: f32,
+}
+
+impl ThreatTable {
+    pub fn get(&self, kind: CombatantKind) -> f32 {
+        match kind {
+            CombatantKind::Fighter => self.fighter,
+            CombatantKind::Ranger => self.ranger,
+            CombatantKind::Mage => self.mage,
+            CombatantKind::Enemy(..) => 0.,
+        }
+    }
+
+    pub fn add(&mut self, kind: CombatantKind, amount: f32) {
+        match kind {
+            CombatantKind::Fighter => self.fighter += amount,
+            CombatantKind::Ranger => self.ranger += amount,
+            CombatantKind::Mage => self.mage += amount,
+            // enemies don't care what the other enemies do
+            CombatantKind::Enemy(..) => {},
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (CombatantKind, f32)> {
+        [
+            (CombatantKind::Fighter, self.fighter),
+            (CombatantKind::Ranger, self.ranger),
+            (CombatantKind::Mage, self.mage),
+        ].into_iter()
+    }
+
+    // the explorer with the most threat, ties go to whoever comes first in the party
+    pub fn target(&self) -> Option<CombatantKind> {
+        self.iter()
+            .filter(|(_, threat)| *threat > 0.)
+            .min_by_key(|(_, threat)| Reverse(F32Ord(*threat)))
+            .map(|(kind, _)| kind)
+    }
+
+    // most threatening explorers first, the order stays as it is for ties so without threat the fighter is in front
+    pub fn sort(&self, party: &mut [&mut Combatant]) {
+        party.sort_by_key(|c| Reverse(F32Ord(self.get(c.kind))));
+    }
+}
+
+// healing, shielding and taunting builds threat with every enemy around, damage is handled when the hit lands
+pub fn generate<'a>(stats: &SkillStats, enemies: impl Iterator<Item = &'a mut Combatant>) {
+    let (user, threat) = match stats {
+        SkillStats::Attack(..) | SkillStats::Dot(..) => return,
+        SkillStats::Defend(_, def) => (def.defender, (def.healed + def.shielded) * SUPPORT_THREAT + def.taunted),
+        SkillStats::Support(_, support) => (support.supporter, (support.healed + support.shielded) * SUPPORT_THREAT),
+    };
+    if threat > 0. {
+        enemies.for_each(|e| e.threat.add(user, threat));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        combat::{enemy::EnemyKind, skill::{defend::DefStats, skill::{SkillKind, SkillSource}, support::SupportStats}},
+        equipment::equipment::{FighterEquip, MageEquip, RangerEquip},
+    };
+
+    use super::*;
+
+    // every combatant has a threat table, only enemies use it but any of them can listen
+    fn listener() -> Combatant {
+        Combatant::fighter(&FighterEquip::default())
+    }
+    fn source() -> SkillSource {
+        SkillSource::Enemy { kind: SkillKind::Defend, cd: 0 }
+    }
+
+    #[test]
+    fn target_is_the_explorer_with_most_threat() {
+        let mut threat = ThreatTable::default();
+        assert_eq!(threat.target(), None);
+
+        threat.add(CombatantKind::Fighter, 10.);
+        threat.add(CombatantKind::Mage, 10.);
+        assert_eq!(threat.target(), Some(CombatantKind::Fighter));
+
+        threat.add(CombatantKind::Ranger, 30.);
+        threat.add(CombatantKind::Enemy(0, EnemyKind::Orc), 100.);
+        assert_eq!(threat.target(), Some(CombatantKind::Ranger));
+    }
+
+    #[test]
+    fn sort_puts_threat_first_and_keeps_the_party_order_for_ties() {
+        let (mut fighter, mut ranger, mut mage) = (
+            Combatant::fighter(&FighterEquip::default()),
+            Combatant::ranger(&RangerEquip::default()),
+            Combatant::mage(&MageEquip::default()),
+        );
+        let mut threat = ThreatTable::default();
+        threat.add(CombatantKind::Mage, 5.);
+
+        let mut party = vec![&mut fighter, &mut ranger, &mut mage];
+        threat.sort(&mut party);
+        assert_eq!(party.iter().map(|c| c.kind).collect::<Vec<_>>(), vec![CombatantKind::Mage, CombatantKind::Fighter, CombatantKind::Ranger]);
+    }
+
+    #[test]
+    fn support_builds_less_threat_than_taunting() {
+        let mut enemies = [listener(), listener()];
+
+        let def = DefStats { defender: CombatantKind::Fighter, healed: 10., shielded: 10., taunted: 15. };
+        generate(&SkillStats::Defend(source(), def), enemies.iter_mut());
+        let support = SupportStats { supporter: CombatantKind::Mage, target: CombatantKind::Fighter, healed: 20., shielded: 20. };
+        generate(&SkillStats::Support(source(), support), enemies.iter_mut());
+
+        for enemy in &enemies {
+            assert_eq!(enemy.threat.get(CombatantKind::Fighter), 25.);
+            assert_eq!(enemy.threat.get(CombatantKind::Mage), 20.);
+            assert_eq!(enemy.threat.target(), Some(CombatantKind::Fighter));
+        }
+    }
+}
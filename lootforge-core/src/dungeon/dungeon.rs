@@ -0,0 +1,197 @@
+use enumset::EnumSet;
+use rand_chacha::ChaCha12Rng;
+
+use crate::dungeon::dungeon_data::DungeonTick;
+use crate::dungeon::floor::{Floor, LevelTick, RoutePolicy};
+use crate::dungeon::modifier::DungeonModifier;
+use crate::dungeon::stop::{StopReason, StopRules};
+use crate::prelude::*;
+use crate::combat::enemy::{EnemyAffinity, EnemyKind, EnemyType};
+use crate::elemental::Element;
+use crate::equipment::wardrobe::{EquipmentSet, OwningEquipmentSet};
+use crate::item::{item::Item, item_type::ItemType};
+
+use super::{reward::{ChestSource, RewardChest}, stats::CombatStats};
+
+#[derive(Debug, Clone)]
+pub struct Dungeon {
+    pub tick: u64,
+    pub area: Area,
+    pub floor: Floor,
+    pub finished: bool,
+    pub cancelled: bool,
+    // the rules the run started with, later changes only apply to the next one
+    pub stop_rules: StopRules,
+    pub stop_reason: Option<StopReason>,
+    pub starting_equip: OwningEquipmentSet,
+    pub rng: ChaCha12Rng,
+    pub battle_stats: CombatStats,
+    pub run_stats: CombatStats,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Area {
+    pub background: Background,
+    // every EnemyType needs at least one kind in here
+    pub enemies: &'static [EnemyKind],
+    // enemies lean towards dealing this element, rewards towards mods of it
+    pub element: Element,
+    // enemies lean towards being weak to this one
+    pub weakness: Element,
+}
+
+#[apply(UnitEnum)]
+#[derive(Default)]
+pub enum Background {
+    Forest,
+    RedForest,
+    Snow,
+    Desert,
+    Cave,
+    #[default]
+    Dungeon,
+}
+
+impl Dungeon {
+    pub fn dummy() -> Self {
+        // bit of a hack to start the game with a "finished" run
+        Self {
+            tick: 0,
+            area: Area::new(Default::default()),
+            floor: Floor::dummy(),
+            finished: true,
+            cancelled: false,
+            stop_rules: StopRules::default(),
+            stop_reason: None,
+            starting_equip: OwningEquipmentSet::default(),
+            rng: ChaCha12Rng::from_os_rng(),
+            battle_stats: CombatStats::default(),
+            run_stats: CombatStats::default(),
+        }
+    }
+    pub fn new(equip: &EquipmentSet, background: Background, route_policy: RoutePolicy, modifiers: EnumSet<DungeonModifier>, stop_rules: StopRules, seed: [u8; 32]) -> Self {
+        let mut rng = ChaCha12Rng::from_seed(seed);
+        let area = Area::new(background);
+        Self {
+            tick: 0,
+            area,
+            floor: Floor::new(equip, &area, route_policy, modifiers, &mut rng),
+            finished: false,
+            cancelled: false,
+            stop_rules,
+            stop_reason: None,
+            starting_equip: OwningEquipmentSet::from(equip),
+            rng,
+            battle_stats: CombatStats::default(),
+            run_stats: CombatStats::default(),
+        }
+    }
+}
+
+impl Area {
+    // chance for each part of an enemy affinity to follow the area instead of rolling freely
+    const ELEMENT_BIAS: f64 = 0.5;
+    // chance to roll a reward again if it has nothing of the area element
+    const LOOT_BIAS: f64 = 0.5;
+
+    pub fn new(background: Background) -> Self {
+        use Background::*;
+        use EnemyKind::*;
+        let (enemies, element, weakness): (&'static [EnemyKind], _, _) = match background {
+            Forest    => (&[Bat, Spider, Orc, Minotaur, BigWorn, MinotaurKing, BroodMother], Element::Bleed, Element::Void),
+            RedForest => (&[Spider, Orc, Minotaur, BigSpider, BroodMother], Element::Bleed, Element::Madness),
+            Snow      => (&[Bat, Skeleton, Minotaur, BigWorn, MinotaurKing, BoneLord], Element::Fracture, Element::Bleed),
+            Desert    => (&[Spider, Skeleton, Orc, Minotaur, BigWorn, BigSpider, BoneLord], Element::Fracture, Element::Void),
+            Cave      => (&[Bat, Spider, Orc, Minotaur, BigWorn, BigSpider, BroodMother], Element::Madness, Element::Fracture),
+            Dungeon   => (EnemyKind::VARIANTS, Element::Void, Element::Madness),
+        };
+        Self { background, enemies, element, weakness }
+    }
+
+    pub fn pick_enemy(&self, etype: EnemyType, rng: &mut impl Rng) -> EnemyKind {
+        let kinds: Vec<_> = self.enemies.iter().filter(|k| k.etype() == etype).collect();
+        **kinds.pick(rng)
+    }
+
+    pub fn roll_affinity(&self, rng: &mut impl Rng) -> EnemyAffinity {
+        let mut affinity = EnemyAffinity::random(rng);
+        if rng.random_bool(Self::ELEMENT_BIAS) {
+            affinity.damage_type = self.element;
+        }
+        if rng.random_bool(Self::ELEMENT_BIAS) {
+            affinity.weakness = self.weakness;
+        }
+        affinity
+    }
+
+    pub fn roll_item(&self, rng: &mut impl Rng, item_type: ItemType, rank: u8) -> Item {
+        let item = Item::random(rng, item_type, rank);
+        let matches = item.mods.iter().any(|m| m.mod_type().mentions(self.element));
+        if !matches && rng.random_bool(Self::LOOT_BIAS) {
+            Item::random(rng, item_type, rank)
+        } else {
+            item
+        }
+    }
+}
+
+impl Dungeon {
+    pub fn tick(&mut self) -> (Option<DungeonTick>, Option<RewardChest>) {
+        if self.finished {
+            return (None, None);
+        }
+        if self.cancelled {
+            self.finished = true;
+            return (
+                None,
+                Some(RewardChest::from(&mut self.rng, &self.area, self.floor.modifiers, self.floor.depth - 1)),
+            );
+        }
+
+        self.tick += 1;
+        
+        if self.tick == 1 {
+            return (Some(DungeonTick { new_battle: true, ..Default::default() }), None);
+        }
+
+        let level_tick = self.floor.tick(&self.area, &mut self.rng);
+        // the walk ends with a Waiting tick that starts the battle
+        let battle_starting = matches!(level_tick, LevelTick::Waiting) && self.floor.transition.is_none();
+        let (tick, chest) = match level_tick {
+            LevelTick::Waiting => (None, None),
+            LevelTick::DungeonTick(dungeon_tick) => (Some(dungeon_tick), None),
+            LevelTick::BossDefeated(dungeon_tick, depth) => (Some(dungeon_tick), Some(RewardChest::boss(&mut self.rng, &self.area, self.floor.modifiers, depth))),
+            LevelTick::ChestWon(dungeon_tick, depth) => (Some(dungeon_tick), Some(RewardChest {
+                source: ChestSource::Fork,
+                ..RewardChest::from(&mut self.rng, &self.area, self.floor.modifiers, depth)
+            })),
+            LevelTick::Lost(depth) => {
+                self.finished = true;
+                self.stop_reason = Some(StopReason::Died);
+                (None, Some(RewardChest::from(&mut self.rng, &self.area, self.floor.modifiers, depth - 1)))
+            },
+        };
+
+        if let Some(tick) = &tick {
+            if tick.new_battle {
+                self.battle_stats = CombatStats::default();
+            }
+            self.battle_stats.record(tick);
+            self.run_stats.record(tick);
+        }
+
+        // part of the tick so retreats replay the same way when loading and catching up
+        if !self.finished && !self.cancelled {
+            if let Some(reason) = self.stop_rules.retreat(self.tick, &self.floor, battle_starting) {
+                self.stop_reason = Some(reason);
+                self.cancelled = true;
+            }
+        }
+        (tick, chest)
+    }
+
+    pub fn cancel(&mut self) {
+        self.cancelled = true;
+        self.stop_reason.get_or_insert(StopReason::Cancelled);
+    }
+}
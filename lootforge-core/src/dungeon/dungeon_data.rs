@@ -0,0 +1,136 @@
+use std::{collections::{BTreeMap, VecDeque}, mem, rc::Rc};
+
+use enumset::EnumSet;
+
+use crate::prelude::*;
+
+use super::{dungeon::{Background, Dungeon}, floor::RoutePolicy, modifier::DungeonModifier, replay::RunRecord, reward::RewardChest, stats::CombatStats, stop::{StopReason, StopRules}};
+use crate::{combat::{event::CombatEvent, skill::skill::SkillStats}, equipment::wardrobe::{EquipmentSet, Wardrobe}};
+
+#[derive(Debug, SmartDefault)]
+pub struct DungeonData {
+    // all of them tick at the same time, every wardrobe set has at most one
+    #[default(vec![DungeonRun::default()])]
+    pub runs: Vec<DungeonRun>,
+    // the run the dungeon panel shows
+    pub selected: usize,
+    pub rewards: BTreeMap<u16, Vec<RewardChest>>,
+}
+
+#[derive(Debug, SmartDefault)]
+pub struct DungeonRun {
+    // the wardrobe set the run fights with, a new set is only picked up on restart
+    pub wardrobe: usize,
+    #[default(Dungeon::dummy())]
+    pub cur: Dungeon,
+    pub auto_restart: bool,
+    // where the next run goes, the current one keeps the area it started in
+    pub area: Background,
+    pub route_policy: RoutePolicy,
+    pub modifiers: EnumSet<DungeonModifier>,
+    pub stop_rules: StopRules,
+    // why the last run ended, or why auto restart gave up after it
+    pub last_stop: Option<StopReason>,
+    // runs in a row that died before the depth of the early deaths rule
+    pub early_deaths: u16,
+    // stats of the previous run, so they are still around after an auto restart
+    pub last_run: Option<CombatStats>,
+    // the last finished runs, newest first, to watch them again
+    pub history: VecDeque<Rc<RunRecord>>,
+}
+impl DungeonData {
+    pub fn selected(&self) -> &DungeonRun {
+        &self.runs[self.selected]
+    }
+    pub fn selected_mut(&mut self) -> &mut DungeonRun {
+        &mut self.runs[self.selected]
+    }
+
+    // the new run starts out finished, like the one at the start of the game
+    pub fn add_run(&mut self, wardrobe: usize) {
+        self.runs.push(DungeonRun { wardrobe, ..Default::default() });
+        self.selected = self.runs.len() - 1;
+    }
+    pub fn remove_run(&mut self, idx: usize) {
+        if self.runs.len() > 1 {
+            self.runs.remove(idx);
+            self.selected = self.selected.min(self.runs.len() - 1);
+        }
+    }
+    // the wardrobe sets no run fights with yet
+    pub fn free_wardrobes<'a>(&'a self, wardrobe: &Wardrobe) -> impl Iterator<Item = usize> + 'a {
+        (0..wardrobe.sets.len()).filter(|w| self.runs.iter().all(|r| r.wardrobe != *w))
+    }
+
+    // only the tick of the selected run is returned, the others play out unseen
+    pub fn tick(&mut self, wardrobe: &Wardrobe) -> Option<DungeonTick> {
+        let mut selected_tick = None;
+        for (i, run) in self.runs.iter_mut().enumerate() {
+            let (tick, reward) = run.tick(&wardrobe.sets[run.wardrobe]);
+            if let Some(reward) = reward {
+                self.rewards.entry(reward.depth).or_default().push(reward);
+            }
+            if i == self.selected {
+                selected_tick = tick;
+            }
+        }
+        selected_tick
+    }
+}
+
+impl DungeonRun {
+    const HISTORY: usize = 10;
+
+    pub fn restart(&mut self, equipment: &EquipmentSet) {
+        let mut seed = [0; 32];
+        rand::rng().fill_bytes(&mut seed);
+        let prev = mem::replace(&mut self.cur, Dungeon::new(equipment, self.area, self.route_policy, self.modifiers, self.stop_rules, seed));
+        // the dummy run at the start of the game has nothing to show
+        if prev.tick > 0 {
+            self.last_run = Some(prev.run_stats);
+        }
+    }
+
+    fn tick(&mut self, equipment: &EquipmentSet) -> (Option<DungeonTick>, Option<RewardChest>) {
+        let (tick, reward) = self.cur.tick();
+        let Some(mut reward) = reward else {
+            return (tick, None);
+        };
+        // boss chests drop in the middle of a run
+        if self.cur.finished {
+            self.record_stop();
+            if self.auto_restart {
+                self.restart(equipment);
+            }
+        }
+        if reward.items.is_empty() {
+            return (tick, None);
+        }
+        reward.wardrobe = self.wardrobe;
+        (tick, Some(reward))
+    }
+
+    fn record_stop(&mut self) {
+        self.history.push_front(Rc::new(RunRecord::from(&self.cur)));
+        self.history.truncate(Self::HISTORY);
+
+        self.last_stop = self.cur.stop_reason;
+        if self.cur.stop_reason == Some(StopReason::Died) && self.stop_rules.early_death(self.cur.floor.depth) {
+            self.early_deaths += 1;
+        } else {
+            self.early_deaths = 0;
+        }
+        if self.stop_rules.early_deaths.is_some_and(|early| self.early_deaths >= early.runs) {
+            self.early_deaths = 0;
+            self.auto_restart = false;
+            self.last_stop = Some(StopReason::EarlyDeaths);
+        }
+    }
+}
+
+#[apply(Default)]
+pub struct DungeonTick {
+    pub new_battle: bool,
+    pub skills: Vec<SkillStats>,
+    pub events: Vec<CombatEvent>,
+}
@@ -0,0 +1,210 @@
+use enumset::EnumSet;
+
+use crate::{combat::enemy::{EnemyAffinity, EnemyType}, dungeon::{dungeon::Area, modifier::DungeonModifier}, prelude::*};
+
+use EncounterDifficulty::*;
+
+#[apply(UnitEnum)]
+pub enum EncounterDifficulty {
+    Easy,
+    Medium,
+    Hard,
+    Boss
+}
+
+#[derive(Debug, Clone)]
+pub struct Encounter {
+    pub difficulty: EncounterDifficulty,
+    pub enemies: Vec<EnemyType>,
+    // one per enemy, rolled with the floor so they can be shown before the fight
+    pub affinities: Vec<EnemyAffinity>,
+    // the encounters that can follow this one, the floor ends after one without any
+    pub next: Vec<usize>,
+    // winning drops a chest, bosses have their own
+    pub chest: bool,
+}
+
+impl Encounter {
+    // chance for every encounter between the first and the last to get a second way around it
+    const FORK_CHANCE: f64 = 0.4;
+
+    fn new(difficulty: EncounterDifficulty, enemies: Vec<EnemyType>) -> Self {
+        Self { difficulty, enemies, affinities: Vec::new(), next: Vec::new(), chest: false }
+    }
+
+    // the floor is a map that starts at the first encounter, every step goes to one of the next ones
+    pub fn generate_floor(depth: u16, area: &Area, modifiers: EnumSet<DungeonModifier>, rng: &mut impl Rng) -> Vec<Self> {
+        let mut encounters = Self::generate_encounters(depth, rng);
+        if modifiers.contains(DungeonModifier::Crowded) {
+            // goes in right before the last fight, so bosses still end the floor
+            let extra = if depth < 10 { Self::easy_encounter(rng) } else { Self::medium_encounter(rng) };
+            encounters.insert(encounters.len() - 1, extra);
+        }
+
+        let mut steps: Vec<Vec<usize>> = (0..encounters.len()).map(|i| vec![i]).collect();
+        for step in 1..steps.len() - 1 {
+            if !rng.random_bool(Self::FORK_CHANCE) {
+                continue;
+            }
+            // either a harder fight that is worth a chest, or an easy detour
+            let fork = if rng.random() {
+                let harder = match encounters[step].difficulty {
+                    Easy => Self::medium_encounter(rng),
+                    _ => Self::hard_encounter(rng),
+                };
+                Self { chest: true, ..harder }
+            } else {
+                Self::easy_encounter(rng)
+            };
+            steps[step].push(encounters.len());
+            encounters.push(fork);
+        }
+        for (step, next) in steps.iter().tuple_windows() {
+            step.iter().for_each(|&i| encounters[i].next = next.clone());
+        }
+
+        for encounter in &mut encounters {
+            encounter.affinities = encounter.enemies.iter().map(|_| area.roll_affinity(rng)).collect();
+        }
+        encounters
+    }
+
+    fn generate_encounters(depth: u16, rng: &mut impl Rng) -> Vec<Self> {
+        if depth < 10 {
+            Self::genrate_early_game(depth, rng)
+        } else if depth % 10 == 0 {
+            // the boss replaces the hard encounter at the end of the floor
+            let mut encounters = Self::generate_normal(depth, rng);
+            *encounters.last_mut().unwrap() = Self::boss_encounter();
+            encounters
+        } else {
+            Self::generate_normal(depth, rng)
+        }
+    }
+
+    fn genrate_early_game(depth: u16, rng: &mut impl Rng) -> Vec<Self> {
+        match depth {
+            1 => vec![
+                Self::new(Easy, vec![EnemyType::Small; 1]),
+                Self::new(Easy, vec![EnemyType::Small; 2]),
+                Self::new(Easy, vec![EnemyType::Small; 3]),
+            ],
+            2 => vec![
+                Self::new(Easy, vec![EnemyType::Small; 2]),
+                Self::new(Easy, vec![EnemyType::Small; 3]),
+                Self::new(Easy, vec![EnemyType::Small; 4]),
+            ],
+            3 => vec![
+                Self::new(Easy, vec![EnemyType::Medium; 1]),
+                Self::new(Easy, vec![EnemyType::Medium, EnemyType::Small, EnemyType::Small]),
+                Self::new(Easy, vec![EnemyType::Medium; 2]),
+            ],
+            4 => vec![
+                Self::new(Easy, vec![EnemyType::Small; 6]),
+                Self::new(Easy, vec![EnemyType::Tank]),
+                Self::new(Medium, vec![EnemyType::Small, EnemyType::Small, EnemyType::Medium, EnemyType::Medium]),
+            ],
+            5 => vec![
+                Self::new(Easy, vec![EnemyType::Dps, EnemyType::Dps]),
+                Self::new(Easy, vec![EnemyType::Tank, EnemyType::Small, EnemyType::Small, EnemyType::Small]),
+                Self::new(Medium, vec![EnemyType::Tank, EnemyType::Medium, EnemyType::Small, EnemyType::Dps]),
+            ],
+            6 | 7 => vec![Self::easy_encounter(rng), Self::easy_encounter(rng), Self::medium_encounter(rng)],
+            8 | 9 => vec![Self::easy_encounter(rng), Self::medium_encounter(rng), Self::medium_encounter(rng)],
+            _ => panic!(),
+        }
+    }
+
+    fn generate_normal(depth: u16, rng: &mut impl Rng) -> Vec<Self> {
+        let battles = 3 + (depth / 10);
+        let (mut easy, mut medium, mut hard) = (battles / 3, battles / 3, battles / 3);
+
+        let remainder = battles % 3;
+        [Easy, Medium, Hard].pick_multiple(rng, remainder as usize)
+            .for_each(|d| match d {
+                Easy => easy += 1,
+                Medium => medium += 1,
+                Hard => hard += 1,
+                Boss => panic!(),
+            });
+        
+        let mut encounters: Vec<Option<EncounterDifficulty>> = vec![None; battles as usize];
+        encounters[0] = Some(Easy);
+        easy -= 1;
+        *encounters.last_mut().unwrap() = Some(Hard);
+        hard -= 1;
+
+        let mut open_indices: Vec<_> = (1..encounters.len()-2).collect();
+        while hard > 0 {
+            let idx = *open_indices.pick(rng);
+            open_indices.retain(|&i| i < idx -1 || i > idx +1);
+
+            encounters[idx] = Some(Hard);
+            hard -= 1;
+        }
+
+        let encounters: Vec<_> = encounters.into_iter().map(|e| e.unwrap_or_else(|| {
+            if rng.random_bool(easy as f64 / medium as f64) {
+                easy -= 1;
+                Easy
+            } else {
+                medium -= 1;
+                Medium
+            }
+        })).collect();                
+        encounters.into_iter().map(|e| match e {
+            Easy => Self::easy_encounter(rng),
+            Medium => Self::medium_encounter(rng),
+            Hard => Self::hard_encounter(rng),
+            Boss => panic!(),
+        }).collect()
+    }
+    
+    fn easy_encounter(rng: &mut impl Rng) -> Self {
+        static FIRST_CHOICE: [&[EnemyType]; 3] = [
+            &[EnemyType::Small; 2],
+            &[EnemyType::Small; 3],
+            &[EnemyType::Medium],
+        ];
+        static SECOND_CHOICE: [&[EnemyType]; 2] = [
+            &[EnemyType::Small; 3],
+            &[EnemyType::Medium],
+        ];
+
+        let enemies = chain!(
+            FIRST_CHOICE.pick(rng).iter(),
+            SECOND_CHOICE.pick(rng).iter(),
+        ).copied().collect();
+
+        Self::new(Easy, enemies)
+    }
+
+    fn medium_encounter(rng: &mut impl Rng) -> Self {
+        static FIRST_CHOICE: [&[EnemyType]; 4] = [
+            &[],
+            &[EnemyType::Small; 2],
+            &[EnemyType::Small; 3],
+            &[EnemyType::Medium],
+        ];
+        static SECOND_CHOICE: [EnemyType; 3] = [EnemyType::Medium, EnemyType::Tank, EnemyType::Dps];
+
+        let enemies = chain!(
+            FIRST_CHOICE.pick(rng).iter().copied(),
+            (0..3).map(|_| *SECOND_CHOICE.pick(rng)),
+        ).collect();
+
+        Self::new(Medium, enemies)
+    }
+
+    fn hard_encounter(rng: &mut impl Rng) -> Self {
+        static CHOICE: [EnemyType; 3] = [EnemyType::Medium, EnemyType::Tank, EnemyType::Dps]; // TODO add special
+        let picks = rng.random_range(5..=6);
+        let enemies = (0..picks).map(|_| *CHOICE.pick(rng)).collect();
+
+        Self::new(Hard, enemies)
+    }
+
+    fn boss_encounter() -> Self {
+        Self::new(Boss, vec![EnemyType::Small, EnemyType::Boss, EnemyType::Small])
+    }
+}
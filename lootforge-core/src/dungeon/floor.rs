@@ -0,0 +1,218 @@
+use enumset::EnumSet;
+
+use crate::combat::enemy::{EnemyAffinity, EnemyKind, EnemyType};
+use crate::dungeon::{dungeon::Area, encounter::Encounter, modifier::DungeonModifier};
+use crate::equipment::wardrobe::EquipmentSet;
+use crate::prelude::*;
+
+use crate::{
+    combat::battle::{Battle, BattleResult},
+    dungeon::dungeon_data::DungeonTick,
+};
+
+#[derive(Debug, Clone)]
+pub struct Floor {
+    pub depth: u16,
+    // how many encounters of the route have been reached
+    pub battle_counter: u16,
+
+    enemy_variation: EnemyVariation,
+    pub encounters: Vec<Encounter>,
+    // the way through the encounters, picked by the policy when the floor is generated
+    pub route: Vec<usize>,
+    pub route_policy: RoutePolicy,
+    pub modifiers: EnumSet<DungeonModifier>,
+
+    pub battle: Battle,
+
+    pub transition: Option<u32>,
+}
+impl Floor {
+    pub fn dummy() -> Self {
+        // bit of a hack to start the game with a "finished" run
+        let mut battle = Battle::new(&Default::default(), Vec::new(), EnumSet::empty(), &mut rand::rng());
+        battle.fighter.health = 0.;
+
+        Self {
+            depth: 0,
+            battle_counter: 0,
+            enemy_variation: EnemyVariation::All,
+            encounters: Vec::new(),
+            route: Vec::new(),
+            route_policy: Default::default(),
+            modifiers: EnumSet::empty(),
+            battle,
+            transition: None,
+        }
+    }
+    pub fn new(equip: &EquipmentSet, area: &Area, route_policy: RoutePolicy, modifiers: EnumSet<DungeonModifier>, rng: &mut impl Rng) -> Self {
+        let enemy_variation = EnemyVariation::new(area, rng);
+        let encounters = Encounter::generate_floor(1, area, modifiers, rng);
+        let route = route_policy.route(&encounters);
+        let battle = Battle::new(equip, enemy_variation.get_all(&encounters[0], area, rng), modifiers, rng);
+        Self {
+            depth: 1,
+            battle_counter: 1,
+            enemy_variation,
+            encounters,
+            route,
+            route_policy,
+            modifiers,
+            battle,
+            transition: Some(Self::TRANSITION_TIME),
+        }
+    }
+}
+
+impl Floor {
+    pub const TRANSITION_TIME: u32 = 50;
+
+    pub fn current(&self) -> usize {
+        self.route[self.battle_counter as usize - 1]
+    }
+
+    // the encounters grouped by how many steps into the floor they are, forks share a step
+    pub fn steps(&self) -> Vec<Vec<usize>> {
+        if self.encounters.is_empty() {
+            return Vec::new();
+        }
+        let mut steps = vec![vec![0]];
+        loop {
+            let next = &self.encounters[steps.last().unwrap()[0]].next;
+            if next.is_empty() {
+                return steps;
+            }
+            steps.push(next.clone());
+        }
+    }
+
+    pub fn tick(&mut self, area: &Area, rng: &mut impl Rng) -> LevelTick {
+        if let Some(ref mut transition) = self.transition {
+            // recovery is spread over the walk to the next battle
+            self.battle.recover(1. / Self::TRANSITION_TIME as f32);
+            *transition -= 1;
+            if *transition == 0 {
+                self.transition = None;
+                self.battle.start();
+            }
+            return LevelTick::Waiting;
+        }
+
+        match self.battle.result() {
+            BattleResult::Ongoing => LevelTick::DungeonTick(self.battle.tick()),
+            BattleResult::Won => {
+                let depth = self.depth;
+                let encounter = &self.encounters[self.current()];
+                let (boss, chest) = (encounter.difficulty.is_boss(), encounter.chest);
+                self.advance(area, rng);
+                let tick = DungeonTick { new_battle: true, ..Default::default() };
+                if boss {
+                    LevelTick::BossDefeated(tick, depth)
+                } else if chest {
+                    LevelTick::ChestWon(tick, depth)
+                } else {
+                    LevelTick::DungeonTick(tick)
+                }
+            }
+            BattleResult::Lost => LevelTick::Lost(self.depth),
+        }
+    }
+
+    pub fn advance(&mut self, area: &Area, rng: &mut impl Rng) {
+        self.transition = Some(Self::TRANSITION_TIME);
+        if self.battle_counter as usize >= self.route.len() {
+            self.battle_counter = 1;
+            self.depth += 1;
+            self.enemy_variation = EnemyVariation::new(area, rng);
+            self.encounters = Encounter::generate_floor(self.depth, area, self.modifiers, rng);
+            self.route = self.route_policy.route(&self.encounters);
+            self.battle.mend_wounds();
+        } else {
+            self.battle_counter += 1;
+        }
+
+        let enemies = self.enemy_variation.get_all(&self.encounters[self.current()], area, rng);
+        self.battle.next(enemies, self.depth, self.modifiers, rng);
+    }
+}
+
+#[apply(Enum)]
+pub enum LevelTick {
+    Waiting,
+    DungeonTick(DungeonTick),
+    BossDefeated(DungeonTick, u16),
+    ChestWon(DungeonTick, u16),
+    Lost(u16),
+}
+
+// the game plays itself, so the way through a floor is decided up front
+#[apply(UnitEnum)]
+#[derive(Default)]
+pub enum RoutePolicy {
+    // always fight for the chest
+    Greedy,
+    // always take the easiest fight
+    Safest,
+    // never leave the main path, so runs stay comparable
+    #[default]
+    Fixed,
+}
+impl RoutePolicy {
+    pub fn route(self, encounters: &[Encounter]) -> Vec<usize> {
+        let mut route = vec![0];
+        loop {
+            let next = &encounters[*route.last().unwrap()].next;
+            if next.is_empty() {
+                return route;
+            }
+            let pick = match self {
+                RoutePolicy::Greedy => next.iter().find(|&&i| encounters[i].chest).unwrap_or(&next[0]),
+                RoutePolicy::Safest => next.iter().min_by_key(|&&i| (encounters[i].difficulty as u8, encounters[i].enemies.len())).unwrap(),
+                RoutePolicy::Fixed => &next[0],
+            };
+            route.push(*pick);
+        }
+    }
+}
+
+#[apply(Enum)]
+enum EnemyVariation {
+    Fixed(EnemyKind, EnemyKind, EnemyKind, EnemyKind),
+    All,
+}
+impl EnemyVariation {
+    fn new(area: &Area, rng: &mut impl Rng) -> Self {
+        if rng.random_bool(1./5.) {
+            Self::All
+        } else {
+            Self::Fixed(
+                area.pick_enemy(EnemyType::Small, rng),
+                area.pick_enemy(EnemyType::Medium, rng),
+                area.pick_enemy(EnemyType::Tank, rng),
+                area.pick_enemy(EnemyType::Dps, rng),
+            )
+        }
+    }
+    // picks from the enemies of the area only
+    fn get(&self, etype: EnemyType, area: &Area, rng: &mut impl Rng) -> EnemyKind {
+        match self {
+            EnemyVariation::Fixed(small, medium, tank, dps) => {
+                match etype {
+                    EnemyType::Small => *small,
+                    EnemyType::Medium => *medium,
+                    EnemyType::Tank => *tank,
+                    EnemyType::Dps => *dps,
+                    // bosses are never fixed, every boss floor rolls its own
+                    EnemyType::Boss => area.pick_enemy(etype, rng),
+                }
+            },
+            EnemyVariation::All => area.pick_enemy(etype, rng),
+        }
+    }
+    fn get_all(&self, encounter: &Encounter, area: &Area, rng: &mut impl Rng) -> Vec<(EnemyKind, EnemyAffinity)> {
+        encounter.enemies.iter()
+            .zip(&encounter.affinities)
+            .map(|(&t, &affinity)| (self.get(t, area, rng), affinity))
+            .collect()
+    }
+}
\ No newline at end of file
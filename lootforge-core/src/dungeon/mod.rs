@@ -2,4 +2,8 @@ pub mod encounter;
 pub mod dungeon;
 pub mod dungeon_data;
 pub mod floor;
+pub mod modifier;
+pub mod replay;
 pub mod reward;
+pub mod stats;
+pub mod stop;
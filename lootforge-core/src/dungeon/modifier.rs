@@ -0,0 +1,56 @@
+use enumset::EnumSetType;
+
+use crate::combat::hooks::CombatHooks;
+use crate::prelude::*;
+
+// picked before a run to make it harder, every one of them makes the rewards better
+#[repr(u8)]
+#[apply(UnitEnum)]
+#[derive(EnumSetType)]
+#[enumset(no_super_impls)]
+pub enum DungeonModifier {
+    Brutal,
+    Warded,
+    Shielded,
+    Hasty,
+    Crowded,
+}
+
+impl DungeonModifier {
+    pub fn describe(self) -> &'static str {
+        match self {
+            DungeonModifier::Brutal => "Enemies deal 30% more damage",
+            DungeonModifier::Warded => "Enemies have 15 more resistance to everything",
+            DungeonModifier::Shielded => "Enemies start battles with a shield of 25% of their max health",
+            DungeonModifier::Hasty => "Enemy cooldowns recover 20% faster",
+            DungeonModifier::Crowded => "Every floor has one more encounter",
+        }
+    }
+
+    // hooks every enemy gets, the ones that change the floor instead are handled when generating it
+    pub fn register(self, hooks: &mut CombatHooks) {
+        match self {
+            DungeonModifier::Brutal => hooks.on_pre_hit(|attack, _skill, _user, _target| attack.damage_mult = attack.damage_mult * 1.3),
+            DungeonModifier::Warded => hooks.on_char(|char| char.resistances = char.resistances + 15.),
+            DungeonModifier::Shielded => hooks.on_combat_start(|effects, _user| effects.shield_from_max_health += 0.25),
+            DungeonModifier::Hasty => hooks.on_char(|char| char.speed += 0.2),
+            DungeonModifier::Crowded => {},
+        }
+    }
+
+    // more items in the chest at the end of the run
+    pub fn bonus_items(self) -> u16 {
+        match self {
+            DungeonModifier::Crowded => 1,
+            _ => 0,
+        }
+    }
+    // added to the chance that the best item of a chest is one rank higher
+    pub fn bonus_overrank_chance(self) -> f64 {
+        match self {
+            DungeonModifier::Brutal | DungeonModifier::Warded => 0.15,
+            DungeonModifier::Shielded | DungeonModifier::Hasty => 0.1,
+            DungeonModifier::Crowded => 0.,
+        }
+    }
+}
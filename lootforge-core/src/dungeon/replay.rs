@@ -0,0 +1,195 @@
+use std::rc::Rc;
+
+use enumset::EnumSet;
+
+use crate::equipment::wardrobe::OwningEquipmentSet;
+
+use super::{
+    dungeon::{Background, Dungeon},
+    dungeon_data::DungeonTick,
+    floor::RoutePolicy,
+    modifier::DungeonModifier,
+    stop::{StopReason, StopRules},
+};
+
+// everything needed to simulate a finished run again, tick by tick
+#[derive(Debug)]
+pub struct RunRecord {
+    pub seed: [u8; 32],
+    pub starting_equip: OwningEquipmentSet,
+    pub background: Background,
+    pub route_policy: RoutePolicy,
+    pub modifiers: EnumSet<DungeonModifier>,
+    pub stop_rules: StopRules,
+    pub ticks: u64,
+    pub depth: u16,
+    pub stop_reason: Option<StopReason>,
+}
+
+impl RunRecord {
+    pub fn from(dungeon: &Dungeon) -> Self {
+        Self {
+            seed: dungeon.rng.get_seed(),
+            starting_equip: OwningEquipmentSet::from(&dungeon.starting_equip.equipment_set),
+            background: dungeon.area.background,
+            route_policy: dungeon.floor.route_policy,
+            modifiers: dungeon.floor.modifiers,
+            stop_rules: dungeon.stop_rules,
+            ticks: dungeon.tick,
+            depth: dungeon.floor.depth,
+            stop_reason: dungeon.stop_reason,
+        }
+    }
+
+    fn dungeon(&self) -> Dungeon {
+        Dungeon::new(&self.starting_equip.equipment_set, self.background, self.route_policy, self.modifiers, self.stop_rules, self.seed)
+    }
+}
+
+#[derive(Debug)]
+pub struct Replay {
+    pub record: Rc<RunRecord>,
+    pub dungeon: Dungeon,
+    // tick and depth of every battle the scout got to so far
+    pub battles: Vec<(u64, u16)>,
+    // copies of the run every SNAPSHOT_TICKS ticks, oldest first
+    snapshots: Vec<Dungeon>,
+    // runs ahead of the replay a chunk at a time, to find the battles and take the snapshots
+    scout: Dungeon,
+}
+
+impl Replay {
+    const SNAPSHOT_TICKS: u64 = 500;
+    const SCOUT_TICKS: u64 = 1000;
+
+    pub fn new(record: Rc<RunRecord>) -> Self {
+        let dungeon = record.dungeon();
+        Self { snapshots: vec![dungeon.clone()], scout: dungeon.clone(), dungeon, battles: Vec::new(), record }
+    }
+
+    // runs that were cancelled never finish, they stop at the tick they were left at
+    fn ended(dungeon: &Dungeon, record: &RunRecord) -> bool {
+        dungeon.finished || dungeon.tick >= record.ticks
+    }
+    pub fn at_end(&self) -> bool {
+        Self::ended(&self.dungeon, &self.record)
+    }
+    pub fn scouted(&self) -> bool {
+        Self::ended(&self.scout, &self.record)
+    }
+
+    // meant to be called every frame, so opening a long run doesn't simulate all of it at once
+    pub fn scout(&mut self) {
+        let until = self.scout.tick + Self::SCOUT_TICKS;
+        self.scout_until(until);
+    }
+    fn scout_until(&mut self, tick: u64) {
+        while self.scout.tick < tick && !self.scouted() {
+            let (dungeon_tick, _) = self.scout.tick();
+            if dungeon_tick.is_some_and(|t| t.new_battle) {
+                self.battles.push((self.scout.tick, self.scout.floor.depth));
+            }
+            if self.scout.tick % Self::SNAPSHOT_TICKS == 0 {
+                self.snapshots.push(self.scout.clone());
+            }
+        }
+    }
+
+    pub fn step(&mut self) -> Option<DungeonTick> {
+        if self.at_end() {
+            return None;
+        }
+        self.dungeon.tick().0
+    }
+
+    // there is no way to go back a tick, so seeking starts from the last snapshot before it
+    pub fn seek(&mut self, tick: u64) {
+        self.scout_until(tick);
+        let snapshot = self.snapshots.iter().rev().find(|s| s.tick <= tick).unwrap();
+        if tick < self.dungeon.tick || snapshot.tick > self.dungeon.tick {
+            self.dungeon = snapshot.clone();
+        }
+        while self.dungeon.tick < tick && !self.at_end() {
+            self.dungeon.tick();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_chacha::ChaCha12Rng;
+
+    use crate::{
+        equipment::{equipment::Equip, wardrobe::{EquipmentSet, ItemSlot}},
+        item::{item::Item, item_type::ItemType},
+        prelude::*,
+        stash::stash::Stash,
+        storage::ser_v2::dungeon_checksum,
+    };
+
+    use super::*;
+
+    fn party_set(stash: &mut Stash, seed: u64) -> EquipmentSet {
+        let mut rng = ChaCha12Rng::seed_from_u64(seed);
+        let mut item = |item_type| {
+            stash.add(Item::random(&mut rng, item_type, 5));
+            Rc::downgrade(stash.items().last().unwrap())
+        };
+        let mut set = EquipmentSet::default();
+        set.fighter_equip.set_item(item(ItemType::Axe), ItemSlot::Weapon(0));
+        set.fighter_equip.set_item(item(ItemType::Armor), ItemSlot::Armor);
+        set.ranger_equip.set_item(item(ItemType::Crossbow), ItemSlot::Weapon(0));
+        set.ranger_equip.set_item(item(ItemType::Satchel), ItemSlot::RangerSatchel);
+        set.mage_equip.set_item(item(ItemType::AttackGem), ItemSlot::Weapon(0));
+        set.mage_equip.set_item(item(ItemType::Staff), ItemSlot::MageStaff);
+        set
+    }
+    fn dungeon(seed: u8) -> Dungeon {
+        let mut stash = Stash::default();
+        let set = party_set(&mut stash, seed as u64);
+        Dungeon::new(&set, Background::Forest, RoutePolicy::default(), EnumSet::empty(), StopRules::default(), [seed; 32])
+    }
+    fn finished(seed: u8) -> Rc<RunRecord> {
+        let mut dungeon = dungeon(seed);
+        while !dungeon.finished {
+            dungeon.tick();
+        }
+        Rc::new(RunRecord::from(&dungeon))
+    }
+
+    #[test]
+    fn a_clone_continues_like_the_original() {
+        let mut original = dungeon(3);
+        for _ in 0..700 {
+            original.tick();
+        }
+        let mut clone = original.clone();
+        while !original.finished {
+            original.tick();
+            clone.tick();
+            assert_eq!(dungeon_checksum(&clone.floor), dungeon_checksum(&original.floor));
+        }
+        assert!(clone.finished);
+    }
+
+    #[test]
+    fn seeking_ends_up_where_simulating_straight_does() {
+        let record = finished(4);
+        let mut replay = Replay::new(record.clone());
+        // back and forth, across and onto snapshots
+        for tick in [1700, 250, 1000, 999, 1501, 0, record.ticks] {
+            replay.seek(tick);
+
+            let mut straight = record.dungeon();
+            while straight.tick < tick {
+                straight.tick();
+            }
+            assert_eq!(replay.dungeon.tick, tick);
+            assert_eq!(dungeon_checksum(&replay.dungeon.floor), dungeon_checksum(&straight.floor), "seeking to {}", tick);
+        }
+        assert!(replay.at_end());
+        assert_eq!(replay.dungeon.floor.depth, record.depth);
+        assert!(replay.snapshots.iter().all(|s| s.tick % Replay::SNAPSHOT_TICKS == 0));
+        assert_eq!(replay.snapshots.len() as u64, record.ticks / Replay::SNAPSHOT_TICKS + 1);
+    }
+}
@@ -0,0 +1,123 @@
+use enumset::EnumSet;
+use rand::distr::weighted::WeightedIndex;
+
+use crate::prelude::*;
+
+use crate::dungeon::{dungeon::Area, modifier::DungeonModifier};
+use crate::item::{item::Item, item_type::ItemType};
+
+#[derive(Debug)]
+pub struct RewardChest {
+    pub depth: u16,
+    pub items: Vec<Item>,
+    // the run that found it, by the wardrobe set it fights with
+    pub wardrobe: usize,
+    pub source: ChestSource,
+}
+
+#[apply(UnitEnum)]
+pub enum ChestSource {
+    // every run gets one when it ends
+    End,
+    Boss,
+    Fork,
+}
+impl RewardChest {
+
+    pub fn from(rng: &mut impl Rng, area: &Area, modifiers: EnumSet<DungeonModifier>, depth: u16) -> Self {
+        if depth == 0 {
+            return Self { depth, items: Vec::new(), wardrobe: 0, source: ChestSource::End };
+        }        
+
+        let count = count(rng, depth) + modifier_items(modifiers);
+        let max_rank = (depth + 10) / 10;
+        let overrank_chance = (overrank_chance(depth) + modifier_overrank_chance(modifiers)).min(1.);
+        let item_types = item_types(depth);
+
+        let mut items = bonus_items(rng, area, max_rank, overrank_chance, item_types, count-1);
+        items.push(max_item(rng, area, max_rank, overrank_chance, item_types));
+        Self { depth, items, wardrobe: 0, source: ChestSource::End }
+    }
+
+    // dropped by every defeated boss, on top of the chest at the end of the run
+    pub fn boss(rng: &mut impl Rng, area: &Area, modifiers: EnumSet<DungeonModifier>, depth: u16) -> Self {
+        let max_rank = (depth + 10) / 10;
+        let overrank_chance = (overrank_chance(depth) + modifier_overrank_chance(modifiers)).min(1.);
+        let item_types = item_types(depth);
+
+        let mut items = bonus_items(rng, area, max_rank, overrank_chance, item_types, modifier_items(modifiers));
+        items.push(max_item(rng, area, max_rank, 1., item_types));
+        // the boss item is always overranked, so the overrank bonus is a chance at a second one
+        let overrank_bonus = modifier_overrank_chance(modifiers).min(1.);
+        if overrank_bonus > 0. && rng.random_bool(overrank_bonus) {
+            items.push(max_item(rng, area, max_rank, 1., item_types));
+        }
+        Self { depth, items, wardrobe: 0, source: ChestSource::Boss }
+    }
+}
+
+fn modifier_items(modifiers: EnumSet<DungeonModifier>) -> u16 {
+    modifiers.iter().map(|m| m.bonus_items()).sum()
+}
+fn modifier_overrank_chance(modifiers: EnumSet<DungeonModifier>) -> f64 {
+    modifiers.iter().map(|m| m.bonus_overrank_chance()).sum()
+}
+
+fn count(rng: &mut impl Rng, depth: u16) -> u16 {
+    if depth < 5 {
+        return 1;
+    }
+
+    let base = (depth+15) / 10;
+    let bonus_chance = (depth+15) % 10;
+    if rng.random_bool((bonus_chance as f64) / 10.) {
+        base + 1
+    } else {
+        base
+    }
+}
+fn overrank_chance(depth: u16) -> f64 {
+    if depth < 5 {
+        0.
+    } else {
+        ((depth % 10) as f64) / 10.0
+    }
+}
+fn item_types(depth: u16) -> &'static[ItemType] {
+    match depth {
+        0 => &[ItemType::Axe, ItemType::Armor], // should not happen
+        1 => &[ItemType::Axe, ItemType::Armor, ItemType::Helmet, ItemType::Shield],
+        2 => &[ItemType::Axe, ItemType::Armor, ItemType::Helmet, ItemType::Shield, ItemType::Gloves],
+        3 => &[ItemType::Axe, ItemType::Armor, ItemType::Helmet, ItemType::Shield, ItemType::Gloves, ItemType::Ring],
+        4..10 => &[ItemType::Axe, ItemType::Armor, ItemType::Helmet, ItemType::Shield, ItemType::Gloves, ItemType::Ring, ItemType::Sword],
+        10..20 => &[ItemType::Axe, ItemType::Armor, ItemType::Helmet, ItemType::Shield, ItemType::Gloves, ItemType::Ring, ItemType::Sword, ItemType::Crossbow, ItemType::Bow, ItemType::Satchel, ItemType::Quiver],
+        // 20.. items for all explorers
+        _ => ItemType::VARIANTS,
+    }
+}
+
+fn bonus_items(rng: &mut impl Rng, area: &Area, max_rank: u16, overrank_chance: f64, item_types: &'static[ItemType], count: u16) -> Vec<Item> {
+    let mut weights = vec![1.0; max_rank as usize];
+    weights.push(overrank_chance);
+
+    let dist = WeightedIndex::new(&weights).unwrap();
+
+    repeat_n((), count as usize)
+        .map(|_| bonus_item(rng, area, &dist, item_types))
+        .collect()
+}
+
+fn bonus_item(rng: &mut impl Rng, area: &Area, dist: &WeightedIndex<f64>, item_types: &'static[ItemType]) -> Item {
+    let item_type = *item_types.pick(rng);
+    let rank = dist.sample(rng) as u8 + 1;
+    area.roll_item(rng, item_type, rank)
+}
+
+fn max_item(rng: &mut impl Rng, area: &Area, max_rank: u16, overrank_chance: f64, item_types: &'static[ItemType]) -> Item {
+    let item_type = *item_types.pick(rng);  
+    if rng.random_bool(overrank_chance) {
+        area.roll_item(rng, item_type, max_rank as u8 +1)
+    } else {
+        area.roll_item(rng, item_type, max_rank as u8)
+    }
+}
\ No newline at end of file
@@ -0,0 +1,138 @@
+use crate::{
+    combat::{
+        buff::BuffIcon,
+        combatant::CombatantKind,
+        enemy::EnemyKind,
+        event::CombatEvent,
+        skill::{hit::HitStats, skill::{SkillSource, SkillStats}},
+    },
+    elemental::Elemental,
+    prelude::*,
+};
+
+use super::dungeon_data::DungeonTick;
+
+// what the party did and what was done to it, collected per battle and for the whole run
+#[apply(Default)]
+pub struct CombatStats {
+    // damage the party dealt to enemies, per skill
+    pub dealt: Vec<SkillDamage>,
+    // damage over time the party inflicted, per skill, debuff and the mod that applied it
+    pub dot_dealt: Vec<DotDamage>,
+
+    pub taken: Elemental<f32>,
+    pub dot_taken: f32,
+    pub mitigated_by_res: f32,
+    pub absorbed_by_shield: f32,
+
+    pub healed: f32,
+    pub shielded: f32,
+    pub blocks: u32,
+    pub evades: u32,
+    pub crits: u32,
+    pub culls: u32,
+    pub kills: Vec<(EnemyKind, u32)>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SkillDamage {
+    pub user: CombatantKind,
+    pub source: SkillSource,
+    pub damage: Elemental<f32>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DotDamage {
+    pub user: CombatantKind,
+    pub source: SkillSource,
+    pub debuff: BuffIcon,
+    pub mod_id: Option<u16>,
+    pub damage: Elemental<f32>,
+}
+
+impl CombatStats {
+    pub fn record(&mut self, tick: &DungeonTick) {
+        tick.skills.iter().for_each(|s| self.record_skill(s));
+        tick.events.iter().for_each(|e| self.record_event(e));
+    }
+
+    fn record_skill(&mut self, stats: &SkillStats) {
+        if let SkillStats::Dot(source, dot) = stats {
+            if dot.target.is_enemy() && !dot.applier.is_enemy() {
+                let dealt = self.dot_damage(dot.applier, source, dot.debuff, dot.mod_id);
+                dealt.damage = dealt.damage + dot.damage;
+            }
+            return;
+        }
+        let SkillStats::Attack(source, attack) = stats else {
+            return;
+        };
+        for HitStats { target, hit, responses } in &attack.hits {
+            if target.is_enemy() {
+                if !attack.attacker.is_enemy() {
+                    let dealt = self.skill_damage(attack.attacker, source);
+                    dealt.damage = dealt.damage + hit.post_res_dmg;
+                }
+            } else {
+                self.taken = self.taken + hit.post_res_dmg;
+                self.mitigated_by_res += hit.pre_res_dmg.sum() - hit.post_res_dmg.sum();
+            }
+            // counters and self hits
+            responses.iter().for_each(|r| self.record_skill(r));
+        }
+    }
+
+    fn skill_damage(&mut self, user: CombatantKind, source: &SkillSource) -> &mut SkillDamage {
+        match self.dealt.iter().position(|d| d.user == user && &d.source == source) {
+            Some(i) => &mut self.dealt[i],
+            None => {
+                self.dealt.push(SkillDamage { user, source: source.clone(), damage: Default::default() });
+                self.dealt.last_mut().unwrap()
+            },
+        }
+    }
+
+    fn dot_damage(&mut self, user: CombatantKind, source: &SkillSource, debuff: BuffIcon, mod_id: Option<u16>) -> &mut DotDamage {
+        let pos = self.dot_dealt.iter()
+            .position(|d| d.user == user && &d.source == source && d.debuff == debuff && d.mod_id == mod_id);
+        match pos {
+            Some(i) => &mut self.dot_dealt[i],
+            None => {
+                self.dot_dealt.push(DotDamage { user, source: source.clone(), debuff, mod_id, damage: Default::default() });
+                self.dot_dealt.last_mut().unwrap()
+            },
+        }
+    }
+
+    fn record_event(&mut self, event: &CombatEvent) {
+        use CombatEvent::*;
+        match *event {
+            Hit { target, absorbed, .. } if !target.is_enemy() => self.absorbed_by_shield += absorbed,
+            Hit { attacker, crit: true, .. } if !attacker.is_enemy() => self.crits += 1,
+            // dealt is recorded from the Dot reports, which know who inflicted it
+            DotTick { target, damage, absorbed, .. } if !target.is_enemy() => {
+                self.dot_taken += damage;
+                self.absorbed_by_shield += absorbed;
+            },
+            Healed { target, amount, .. } if !target.is_enemy() => self.healed += amount,
+            Shielded { target, amount, .. } if !target.is_enemy() => self.shielded += amount,
+            Blocked { target, .. } if !target.is_enemy() => self.blocks += 1,
+            Evaded { target, .. } if !target.is_enemy() => self.evades += 1,
+            Culled { attacker, .. } if !attacker.is_enemy() => self.culls += 1,
+            Died { target: CombatantKind::Enemy(_, kind) } => {
+                match self.kills.iter_mut().find(|(k, _)| *k == kind) {
+                    Some((_, kills)) => *kills += 1,
+                    None => self.kills.push((kind, 1)),
+                }
+            },
+            _ => {},
+        }
+    }
+
+    pub fn total_dealt(&self) -> f32 {
+        self.dealt.iter().map(|d| d.damage.sum()).sum::<f32>() + self.dot_dealt.iter().map(|d| d.damage.sum()).sum::<f32>()
+    }
+    pub fn total_taken(&self) -> f32 {
+        self.taken.sum() + self.dot_taken
+    }
+}
@@ -0,0 +1,146 @@
+use crate::prelude::*;
+
+use super::floor::Floor;
+
+// when a run retreats on its own and when auto restart gives up, all of them are off by default
+#[apply(Default)]
+#[derive(Copy)]
+pub struct StopRules {
+    // retreat once this many floors are cleared
+    pub depth: Option<u16>,
+    // retreat when the party goes into a battle with less than this percent of its health
+    pub health: Option<u8>,
+    pub minutes: Option<u16>,
+    pub early_deaths: Option<EarlyDeaths>,
+}
+
+// stop auto restart once this many runs in a row died before reaching the depth
+#[derive(Debug, Clone, Copy)]
+pub struct EarlyDeaths {
+    pub runs: u16,
+    pub depth: u16,
+}
+
+#[apply(UnitEnum)]
+pub enum StopReason {
+    Died,
+    Cancelled,
+    Depth,
+    Health,
+    Time,
+    // auto restart stopped, the run itself died
+    EarlyDeaths,
+    // the run was in progress in a save that could not bring it back
+    Outdated,
+}
+
+impl StopRules {
+    // the game runs at 10 ticks per second
+    pub const TICKS_PER_MINUTE: u64 = 600;
+
+    // battle_starting is only true on the tick the walk to the next battle ends, after the party recovered
+    pub fn retreat(&self, tick: u64, floor: &Floor, battle_starting: bool) -> Option<StopReason> {
+        if self.depth.is_some_and(|depth| floor.depth > depth) {
+            return Some(StopReason::Depth);
+        }
+        if battle_starting && self.health.is_some_and(|health| floor.battle.party_health() * 100. < health as f32) {
+            return Some(StopReason::Health);
+        }
+        if self.minutes.is_some_and(|minutes| tick >= minutes as u64 * Self::TICKS_PER_MINUTE) {
+            return Some(StopReason::Time);
+        }
+        None
+    }
+
+    pub fn early_death(&self, depth: u16) -> bool {
+        self.early_deaths.is_some_and(|early| depth < early.depth)
+    }
+}
+
+impl StopReason {
+    pub fn describe(self) -> &'static str {
+        match self {
+            StopReason::Died => "The party died",
+            StopReason::Cancelled => "Cancelled",
+            StopReason::Depth => "Retreated at the target depth",
+            StopReason::Health => "Retreated with low health",
+            StopReason::Time => "Retreated after the time limit",
+            StopReason::EarlyDeaths => "Auto restart stopped after too many early deaths",
+            StopReason::Outdated => "The run in progress was lost to a save update",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{dungeon::dungeon_data::DungeonData, equipment::wardrobe::Wardrobe};
+
+    use super::*;
+
+    fn floor(depth: u16, health: f32) -> Floor {
+        let mut floor = Floor::dummy();
+        floor.depth = depth;
+        floor.battle.fighter.health = floor.battle.fighter.stats().max_health * health;
+        floor
+    }
+
+    #[test]
+    fn without_rules_nothing_retreats() {
+        assert_eq!(StopRules::default().retreat(u64::MAX, &floor(100, 0.01), true), None);
+        assert!(!StopRules::default().early_death(0));
+    }
+
+    #[test]
+    fn depth_retreats_once_the_target_floor_is_cleared() {
+        let rules = StopRules { depth: Some(5), ..Default::default() };
+        assert_eq!(rules.retreat(0, &floor(5, 1.), false), None);
+        assert_eq!(rules.retreat(0, &floor(6, 1.), false), Some(StopReason::Depth));
+    }
+
+    #[test]
+    fn health_is_only_checked_when_a_battle_starts() {
+        let rules = StopRules { health: Some(50), ..Default::default() };
+        assert_eq!(rules.retreat(0, &floor(3, 0.4), false), None);
+        assert_eq!(rules.retreat(0, &floor(3, 0.6), true), None);
+        assert_eq!(rules.retreat(0, &floor(3, 0.4), true), Some(StopReason::Health));
+    }
+
+    #[test]
+    fn time_is_counted_in_ticks() {
+        let rules = StopRules { minutes: Some(2), ..Default::default() };
+        assert_eq!(rules.retreat(2 * StopRules::TICKS_PER_MINUTE - 1, &floor(1, 1.), false), None);
+        assert_eq!(rules.retreat(2 * StopRules::TICKS_PER_MINUTE, &floor(1, 1.), false), Some(StopReason::Time));
+    }
+
+    #[test]
+    fn depth_goes_before_health_and_time() {
+        let rules = StopRules { depth: Some(5), health: Some(50), minutes: Some(1), early_deaths: None };
+        assert_eq!(rules.retreat(StopRules::TICKS_PER_MINUTE, &floor(6, 0.1), true), Some(StopReason::Depth));
+        assert_eq!(rules.retreat(StopRules::TICKS_PER_MINUTE, &floor(5, 0.1), true), Some(StopReason::Health));
+    }
+
+    #[test]
+    fn auto_restart_stops_after_enough_early_deaths_in_a_row() {
+        let rules = StopRules { early_deaths: Some(EarlyDeaths { runs: 2, depth: 5 }), ..Default::default() };
+        assert!(rules.early_death(4));
+        assert!(!rules.early_death(5));
+
+        // without any items the party dies on the first floor
+        let wardrobe = Wardrobe::default();
+        let mut dungeon = DungeonData::default();
+        let run = dungeon.selected_mut();
+        run.stop_rules = rules;
+        run.auto_restart = true;
+        run.restart(&wardrobe.sets[0]);
+
+        for _ in 0..100_000 {
+            if !dungeon.selected().auto_restart {
+                break;
+            }
+            dungeon.tick(&wardrobe);
+        }
+        let run = dungeon.selected();
+        assert!(!run.auto_restart);
+        assert_eq!(run.last_stop, Some(StopReason::EarlyDeaths));
+    }
+}
@@ -13,17 +13,6 @@ pub enum Element {
     Madness = 3,
     Void = 4,
 }
-impl Element {
-    pub fn color(&self) -> Color32 {
-        match self {
-            Bleed => Color32::RED,
-            Fracture => Color32::YELLOW,
-            Madness => Color32::CYAN,
-            Void => Color32::PURPLE,
-        }
-    }
-}
-
 #[derive(Debug, Clone, Default)]
 pub struct Elemental<T> {
     pub bleed: T,
@@ -44,7 +33,7 @@ impl<T: Copy> From<T> for Elemental<T> {
 }
 impl<T: Copy> Elemental<T> {
     pub fn with(&self, value: T, element: Element) -> Self {
-        let mut clone = self.clone();
+        let mut clone = *self;
         clone.set(value, element);
         clone
     }
@@ -2,7 +2,7 @@ use std::rc::Rc;
 
 use crate::prelude::*;
 use crate::{equipment::equipment::Equip, explorer::Explorer, item::{item::Item, item::ItemRef, item_type::ItemType}, mods::atk_mod};
-use super::equipment::FighterEquip;
+use super::equipment::{FighterEquip, MageEquip, RangerEquip};
 
 #[apply(Default)]
 pub struct Wardrobe {
@@ -13,6 +13,8 @@ pub struct Wardrobe {
 #[apply(Default)]
 pub struct EquipmentSet {
     pub fighter_equip: FighterEquip,
+    pub ranger_equip: RangerEquip,
+    pub mage_equip: MageEquip,
 }
 
 impl Wardrobe {
@@ -43,10 +45,16 @@ impl Wardrobe {
 impl EquipmentSet {
     pub fn iter(&self) -> impl Iterator<Item = &ItemRef> {
         self.fighter_equip.iter()
+            .chain(self.ranger_equip.iter())
+            .chain(self.mage_equip.iter())
     }
     
     pub fn copy_owned(&self, owned: &mut Vec<Rc<Item>>) -> Self {
-        Self { fighter_equip: self.fighter_equip.copy_owned(owned) }
+        Self {
+            fighter_equip: self.fighter_equip.copy_owned(owned),
+            ranger_equip: self.ranger_equip.copy_owned(owned),
+            mage_equip: self.mage_equip.copy_owned(owned),
+        }
     }
 }
 
@@ -89,14 +97,14 @@ impl ItemSlot {
                 match explorer {
                     Explorer::Fighter => t == ItemType::Axe || t == ItemType::Sword,
                     Explorer::Ranger => t == ItemType::Crossbow || t == ItemType::Bow,
-                    Explorer::Mage => todo!(),
+                    Explorer::Mage => t == ItemType::AttackGem || t == ItemType::AoeGem,
                 }
             }
             FighterShield => t == ItemType::Shield || (t == ItemType::Axe && item.has_mod(atk_mod::LIGHT.id)),
-            RangerQuiver => todo!(),
+            RangerQuiver => t == ItemType::Quiver,
             RangerSatchel => t == ItemType::Satchel,
-            MageSupportGem => todo!(),
-            MageStaff => todo!(),
+            MageSupportGem => t == ItemType::SupportGem,
+            MageStaff => t == ItemType::Staff,
             Helmet => t == ItemType::Helmet,
             Armor => t == ItemType::Armor,
             Gloves => t == ItemType::Gloves,
@@ -110,15 +118,15 @@ impl ItemSlot {
                 match explorer {
                     Explorer::Fighter => Some(ItemType::Axe),
                     Explorer::Ranger => Some(ItemType::Bow),
-                    Explorer::Mage => todo!(),
+                    Explorer::Mage => Some(ItemType::AttackGem),
                 }
                 
             }
             FighterShield => Some(ItemType::Shield),
-            RangerQuiver => todo!(),
+            RangerQuiver => Some(ItemType::Quiver),
             RangerSatchel => Some(ItemType::Satchel),
-            MageSupportGem => todo!(),
-            MageStaff => todo!(),
+            MageSupportGem => Some(ItemType::SupportGem),
+            MageStaff => Some(ItemType::Staff),
             Helmet => Some(ItemType::Helmet),
             Armor => Some(ItemType::Armor),
             Gloves => Some(ItemType::Gloves),
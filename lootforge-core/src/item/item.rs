@@ -0,0 +1,104 @@
+use super::{item_type::ItemType, tags::ItemTags};
+
+use crate::mods::attune::Attunement;
+use crate::prelude::*;
+
+use crate::{
+    combat::skill::{targeting::Targeting},
+    mods::*,
+};
+
+
+#[derive(Debug)]
+pub struct Item {
+    pub id: usize,
+    pub item_type: ItemType,
+    // TODO most items don't even have targeting, but I do need something to store inherit stuff, prolly in itemtype data
+    pub targeting: Option<Targeting>,
+    pub mods: Vec<RolledMod>,
+    pub rerolled_mod_idx: u8,
+    pub tags: ItemTags, // TODO we can add a Box here for pointer and then do some mem optimization, because most items have similar tags
+    pub attunements: Vec<Attunement>,
+}
+
+pub type ItemRef = Weak<Item>;
+
+impl Item {
+    pub fn new(item_type: ItemType, mods: Vec<RolledMod>, targeting: Option<Targeting>) -> Self {
+        let mut item = Self {
+            id: 0,
+            item_type,
+            targeting,
+            mods,
+            rerolled_mod_idx: u8::MAX,
+            tags: ItemTags::default(),
+            attunements: Vec::new(),
+        };
+        item.recompute_attunements();
+        item
+    }
+    pub fn random(rng: &mut impl Rng, item_type: ItemType, rank: u8) -> Self {
+        Self::random_with_mods(rng, item_type, rank, Vec::new())
+    }
+    pub fn random_with_mods(rng: &mut impl Rng, item_type: ItemType, rank: u8, forced_mods: Vec<RolledMod>) -> Self {
+        let mut mods = Vec::with_capacity(rank as usize);
+
+        mods.extend(forced_mods);
+
+        for _ in mods.len()..rank as usize {
+            mods.push(item_type.roll_mod(rng, &mods));
+        }
+
+        let targeting = item_type.roll_targeting(rng);
+
+        Self::new(item_type, mods, targeting)
+    }
+
+    pub fn recompute_attunements(&mut self) {
+        self.attunements = self.mods.iter()
+            .filter_map(|m| m.mod_type().attunement())
+            .map(|(group, idx )| (group.kind, idx))
+            .into_group_map()
+            .iter()
+            .filter(|(_, idx)| idx.iter().all_equal())
+            .map(|(kind, idx)| (*kind, idx[0]))
+            .collect();
+    }
+
+    pub fn rank(&self) -> u8 {
+        self.mods.len().try_into().expect("item has more then 255 mods, WTF")
+    }
+
+    pub fn mod_count(&self, mod_id: u16) -> u8 {
+        self.mods.iter().filter(|m| m.mod_id == mod_id).count() as u8
+    }
+
+    pub fn has_mod(&self, mod_id: u16) -> bool {
+        self.mod_count(mod_id) > 0
+    }
+
+    pub fn has_all_mods(&self, mut mods: impl Iterator<Item = (u16, u8)>) -> bool {
+        mods.all(|(wanted, count)| self.mod_count(wanted) >= count)
+    }
+
+    pub fn rerolled_mod_idx(&self) ->  Option<u8> {
+        if self.rerolled_mod_idx == u8::MAX {
+            None
+        } else {
+            Some(self.rerolled_mod_idx)
+        }
+    }
+}
+
+impl std::ops::Index<u8> for Item {
+    type Output = RolledMod;
+    fn index(&self, i: u8) -> &RolledMod {
+        &self.mods[i as usize]
+    }
+}
+impl PartialEq for Item {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+impl Eq for Item {}
@@ -1,6 +1,7 @@
 use enumset::EnumSetType;
 
 use crate::prelude::*;
+use crate::combat::skill::targeting::Targeting;
 use crate::mods::{atk_mod, def_mod, RolledMod};
 use crate::mods::roll_tables::*;
 
@@ -18,12 +19,13 @@ pub enum ItemType {
     Crossbow = 4,
     Bow = 5,
     Satchel = 6,
-    // Quiver = 7
+    Quiver = 7,
 
     // Mage
-
-    // 3 Skillgems 8,9,10
-    // Staff 11
+    AttackGem = 8,
+    AoeGem = 9,
+    SupportGem = 10,
+    Staff = 11,
 
     // Common
     Armor = 12,
@@ -44,7 +46,16 @@ impl ItemType {
                         return atk_mod::ADDED_DMG.fracture.roll(rng)
                     }
                 },
-                Helmet => return def_mod::SHIELD.roll(rng),
+                AttackGem | AoeGem => {
+                    if rng.random() {
+                        return atk_mod::ADDED_DMG.madness.roll(rng)
+                    } else {
+                        return atk_mod::ADDED_DMG.void.roll(rng)
+                    }
+                },
+                Helmet | SupportGem => return def_mod::SHIELD.roll(rng),
+                Satchel => return def_mod::HEAL.roll(rng),
+                Staff => return def_mod::EMPOWER.roll(rng),
                 _ => {}
             }
         }
@@ -52,11 +63,19 @@ impl ItemType {
         match *self {
             Axe => AXE_ROLL_TABLE.roll_mod(rng, mods).roll(rng), 
             Sword => SWORD_ROLL_TABLE.roll_mod(rng, mods).roll(rng),
-            Crossbow | Bow => panic!(), // attack gems
+            Crossbow => CROSSBOW_ROLL_TABLE.roll_mod(rng, mods).roll(rng),
+            Bow => BOW_ROLL_TABLE.roll_mod(rng, mods).roll(rng),
+            Quiver => QUIVER_ROLL_TABLE.roll_mod(rng, mods).roll(rng),
+            AttackGem => ATTACK_GEM_ROLL_TABLE.roll_mod(rng, mods).roll(rng),
+            AoeGem => AOE_GEM_ROLL_TABLE.roll_mod(rng, mods).roll(rng),
+            SupportGem => SUPPORT_GEM_ROLL_TABLE.roll_mod(rng, mods).roll(rng),
+            Staff => STAFF_ROLL_TABLE.roll_mod(rng, mods).roll(rng),
 
             Gloves => GLOVE_ROLL_TABLE.roll_mod(rng, mods).roll(rng),
 
-            Shield | Satchel => SHIELD_ROLL_TABLE.roll_mod(rng, mods).roll(rng), // util gem
+            Satchel => SATCHEL_ROLL_TABLE.roll_mod(rng, mods).roll(rng),
+
+            Shield => SHIELD_ROLL_TABLE.roll_mod(rng, mods).roll(rng),
             Helmet => HELMET_ROLL_TABLE.roll_mod(rng, mods).roll(rng),
 
             Armor =>  ARMOR_ROLL_TABLE.roll_mod(rng, mods).roll(rng),
@@ -66,35 +85,22 @@ impl ItemType {
 
     pub fn two_handed(&self) -> bool {
         use ItemType::*;
-        match *self {
-            Sword | Bow => true, // TODO add aoe gem
-            _ => false,
-        }
+        matches!(*self, Sword | Bow | AoeGem)
     }
 
     pub fn has_targeting(&self) -> bool {
         use ItemType::*;
-        match *self {
-            Satchel | Ring => true,
-            _ => false,
-        }
+        matches!(*self, Satchel | Helmet | Ring)
     }
 
-    pub const SIZE: Vec2 = vec2(64., 64.);
-    pub fn image(&self) -> Image<'_> {
+    pub fn roll_targeting(&self, rng: &mut impl Rng) -> Option<Targeting> {
         use ItemType::*;
-        let source = match *self {
-            Axe => include_image!("../../assets/items/axe.png"),
-            Sword => include_image!("../../assets/items/sword.png"),
-            Shield => include_image!("../../assets/items/shield.png"),
-            Crossbow => include_image!("../../assets/items/hand_crossbow.png"),
-            Bow => include_image!("../../assets/items/bow.png"),
-            Satchel => include_image!("../../assets/items/satchel.png"),
-            Armor => include_image!("../../assets/items/armor.png"),
-            Helmet => include_image!("../../assets/items/helmet.png"),
-            Gloves => include_image!("../../assets/items/gloves.png"),
-            Ring => include_image!("../../assets/items/ring.png"),
-        };
-        Image::new(source).fit_to_exact_size(Self::SIZE)
+        match *self {
+            Satchel | Ring => Some(Targeting::roll_ring(rng)),
+            // helmets only decide when they trigger
+            Helmet => Some(Targeting::roll_trigger(rng)),
+            _ => None,
+        }
     }
-}
\ No newline at end of file
+}
+
@@ -57,17 +57,3 @@ pub enum Rating {
     Dislike,
     Trash,
 }
-impl Rating {    
-    pub const SIZE: Vec2 = vec2(16., 16.);
-    pub fn image(&self) -> Option<Image<'_>> {
-        use Rating::*;
-        let source = match *self {
-            Favorite => include_image!("../../assets/icons/round-star.png"),
-            Like     => include_image!("../../assets/icons/thumb-up.png"),
-            Neutral  => return None,
-            Dislike  => include_image!("../../assets/icons/thumb-down.png"),
-            Trash   => include_image!("../../assets/icons/trash-can.png"),
-        };
-        Some(Image::new(source).fit_to_exact_size(Self::SIZE))
-    }
-}
@@ -0,0 +1,15 @@
+#![warn(clippy::all, rust_2018_idioms)]
+#![allow(clippy::module_inception)]
+
+pub mod combat;
+pub mod dungeon;
+pub mod elemental;
+pub mod equipment;
+pub mod explorer;
+pub mod item;
+pub mod mods;
+pub mod stash;
+pub mod storage;
+pub mod util;
+
+pub mod prelude;
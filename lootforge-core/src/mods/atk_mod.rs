@@ -13,7 +13,7 @@ pub static ADDED_DMG: Elemental<ModType> = Elemental {
         prefix_name: "lacerating",
         roll_range: 25..=50,
         attune: None,
-        show_tooltip: |this, ui, roll| { tooltip!("Add %roll %range %bleed Damage"); },
+        tooltip: tooltip!("Add %roll %range %bleed Damage"),
         register: |hooks, _item, _equip, roll| {
             hooks.on_pre_hit(move |attack, _skill, _user, _target| attack.damage.bleed += roll as f32);
         },
@@ -23,7 +23,7 @@ pub static ADDED_DMG: Elemental<ModType> = Elemental {
         prefix_name: "shattering",
         roll_range: 25..=50,
         attune: None,
-        show_tooltip: |this, ui, roll| { tooltip!("Add %roll %range %fracture Damage"); },
+        tooltip: tooltip!("Add %roll %range %fracture Damage"),
         register: |hooks, _item, _equip, roll| {
             hooks.on_pre_hit(move |attack, _skill, _user, _target| attack.damage.fracture += roll as f32);
         },
@@ -33,7 +33,7 @@ pub static ADDED_DMG: Elemental<ModType> = Elemental {
         prefix_name: "maddening",
         roll_range: 25..=50,
         attune: None,
-        show_tooltip: |this, ui, roll| { tooltip!("Add %roll %range %madness Damage"); },
+        tooltip: tooltip!("Add %roll %range %madness Damage"),
         register: |hooks, _item, _equip, roll| {
             hooks.on_pre_hit(move |attack, _skill, _user, _target| attack.damage.madness += roll as f32);
         },
@@ -43,7 +43,7 @@ pub static ADDED_DMG: Elemental<ModType> = Elemental {
         prefix_name: "empty",
         roll_range: 25..=50,
         attune: None,
-        show_tooltip: |this, ui, roll| { tooltip!("Add %roll %range %void Damage"); },
+        tooltip: tooltip!("Add %roll %range %void Damage"),
         register: |hooks, _item, _equip, roll| {
             hooks.on_pre_hit(move |attack, _skill, _user, _target| attack.damage.void += roll as f32);
         },
@@ -56,7 +56,7 @@ pub static PENETRATION: Elemental<ModType> = Elemental {
         prefix_name: "sharp",
         roll_range: 10..=20,
         attune: Some(&attune::PENETRATION),
-        show_tooltip: |this, ui, roll| { tooltip!("Overcome %roll %range %bleed Resistance"); },
+        tooltip: tooltip!("Overcome %roll %range %bleed Resistance"),
         register: |hooks, _item, _equip, roll| {
             hooks.on_pre_hit(move |attack, _skill, _user, _target| attack.penetration.bleed += roll as f32);
         },
@@ -66,7 +66,7 @@ pub static PENETRATION: Elemental<ModType> = Elemental {
         prefix_name: "grinding",
         roll_range: 10..=20,
         attune: Some(&attune::PENETRATION),
-        show_tooltip: |this, ui, roll| { tooltip!("Overcome %roll %range %fracture Resistance"); },
+        tooltip: tooltip!("Overcome %roll %range %fracture Resistance"),
         register: |hooks, _item, _equip, roll| {
             hooks.on_pre_hit(move |attack, _skill, _user, _target| attack.penetration.fracture += roll as f32);
         },
@@ -76,7 +76,7 @@ pub static PENETRATION: Elemental<ModType> = Elemental {
         prefix_name: "disturbing",
         roll_range: 10..=20,
         attune: Some(&attune::PENETRATION),
-        show_tooltip: |this, ui, roll| { tooltip!("Overcome %roll %range %madness Resistance"); },
+        tooltip: tooltip!("Overcome %roll %range %madness Resistance"),
         register: |hooks, _item, _equip, roll| {
             hooks.on_pre_hit(move |attack, _skill, _user, _target| attack.penetration.madness += roll as f32);
         },
@@ -86,7 +86,7 @@ pub static PENETRATION: Elemental<ModType> = Elemental {
         prefix_name: "silent", // TODO can do better hollow, vast eternal, perpetual
         roll_range: 10..=20,
         attune: Some(&attune::PENETRATION),
-        show_tooltip: |this, ui, roll| { tooltip!("Overcome %roll %range %void Resistance"); },
+        tooltip: tooltip!("Overcome %roll %range %void Resistance"),
         register: |hooks, _item, _equip, roll| {
             hooks.on_pre_hit(move |attack, _skill, _user, _target| attack.penetration.void += roll as f32);
         },
@@ -98,7 +98,7 @@ pub static CULLING: ModType = ModType {
     prefix_name: "culling",
     roll_range: 5..=10,
     attune: None,
-    show_tooltip: |this, ui, roll| { tooltip!("Kill enemies under %roll% %range of their max HP"); },
+    tooltip: tooltip!("Kill enemies under %roll% %range of their max HP"),
     register: |hooks, _item, _equip, roll| {
         hooks.on_post_hit(move |attack, _skill, _user, _target, _hit| attack.cull_threshhold = (roll as f32).at_least(attack.cull_threshhold));
     },
@@ -109,7 +109,7 @@ pub static LIFESTEAL: ModType = ModType {
     prefix_name: "vampiric",
     roll_range: 4..=8,
     attune: None,
-    show_tooltip: |this, ui, roll| { tooltip!("Steal %roll% %range of Damage dealt as Life"); },
+    tooltip: tooltip!("Steal %roll% %range of Damage dealt as Life"),
     register: |hooks, _item, _equip, roll| {
         hooks.on_post_hit(move |attack, _skill, _user, _target, _hit| attack.life_steal += (roll as f32) / 100.);
     },
@@ -119,7 +119,7 @@ pub static SHIELDSTEAL: ModType = ModType {
     prefix_name: "leeching",
     roll_range: 5..=15,
     attune: None,
-    show_tooltip: |this, ui, roll| { tooltip!("Steal %roll% %range of Damage dealt as Shield"); },
+    tooltip: tooltip!("Steal %roll% %range of Damage dealt as Shield"),
     register: |hooks, _item, _equip, roll| {
         hooks.on_post_hit(move |attack, _skill, _user, _target, _hit| attack.shield_steal += (roll as f32) / 100.);
     },
@@ -131,7 +131,7 @@ pub static DEBUFF_OFF_ST: ModType = ModType {
     prefix_name: "afflicting",
     roll_range: 0..=0,
     attune: None,
-    show_tooltip: |_this, ui, _roll| { tooltip!("Every 2nd attack inflicts the offensive Debuff of the primary damage type"); },
+    tooltip: tooltip!("Every 2nd attack inflicts the offensive Debuff of the primary damage type"),
     register: |hooks, _item, _equip, _roll| {
         hooks.on_post_hit(move |attack, skill, _user, _target, hit| {
             if skill.uses % 2 == 0 {
@@ -150,7 +150,7 @@ pub static DEBUFF_OFF_AOE: ModType = ModType {
     prefix_name: "afflicting",
     roll_range: 0..=0,
     attune: None,
-    show_tooltip: |_this, ui, _roll| { tooltip!("Every 3rd attack inflicts the offensive Debuff of the primary damage type"); },
+    tooltip: tooltip!("Every 3rd attack inflicts the offensive Debuff of the primary damage type"),
     register: |hooks, _item, _equip, _roll| {
         hooks.on_post_hit(move |attack, skill, _user, _target, hit| {
             if skill.uses % 3 == 0 {
@@ -171,7 +171,7 @@ pub static DEBUFF_UTIL_ST: ModType = ModType {
     prefix_name: "impairing",
     roll_range: 0..=0,
     attune: None,
-    show_tooltip: |_this, ui, _roll| { tooltip!("Every 2nd attack gives the enemy the utility debuff of the primary damage type"); },
+    tooltip: tooltip!("Every 2nd attack gives the enemy the utility debuff of the primary damage type"),
     register: |hooks, _item, _equip, _roll| {
         hooks.on_post_hit(move |attack, skill, _user, _target, hit| {
             if skill.uses % 2 == 0 {
@@ -190,7 +190,7 @@ pub static DEBUFF_UTIL_AOE: ModType = ModType {
     prefix_name: "impairing",
     roll_range: 0..=0,
     attune: None,
-    show_tooltip: |_this, ui, _roll| { tooltip!("Every 3rd attack gives the enemy the utility debuff of the primary damage type"); },
+    tooltip: tooltip!("Every 3rd attack gives the enemy the utility debuff of the primary damage type"),
     register: |hooks, _item, _equip, _roll| {
         hooks.on_post_hit(move |attack, skill, _user, _target, hit| {
             if skill.uses % 3 == 0 {
@@ -209,7 +209,7 @@ pub static ATK_READY: ModType = ModType {
     prefix_name: "prepared",
     roll_range: 0..=0,
     attune: None,
-    show_tooltip: |_this, ui, _roll| { tooltip!("Start Combat with the linked items skill ready"); },
+    tooltip: tooltip!("Start Combat with the linked items skill ready"),
     register: |hooks, item, equip, _roll| {
         if let Some(linked_id) = equip.get_linked_item(item).upgrade().map(|i| i.id) {
             hooks.on_combat_start(move |effects, _user| effects.ready_skills.push(linked_id));
@@ -221,7 +221,7 @@ pub static MULTISTRIKE_ST: ModType = ModType {
     prefix_name: "dueling",
     roll_range: 0..=0,
     attune: None,
-    show_tooltip: |_this, ui, _roll| { tooltip!("When your offhand is empty, you main hand strikes one more time"); },
+    tooltip: tooltip!("When your offhand is empty, you main hand strikes one more time"),
     register: |hooks, _item, equip, _roll| {
         let (off_hand, two_handed) = equip.get_item(ItemSlot::Weapon(1));
         if !two_handed && off_hand.upgrade().is_none() {
@@ -242,7 +242,7 @@ pub static MULTISTRIKE_AOE: ModType = ModType {
     prefix_name: "focussed",
     roll_range: 0..=0,
     attune: None,
-    show_tooltip: |_this, ui, _roll| { tooltip!("When there is only 1 target, you strike one more time"); },
+    tooltip: tooltip!("When there is only 1 target, you strike one more time"),
     register: |hooks, _item, _equip, _roll| {
         hooks.on_pre_attack(move |attack, _skill, _user, targets| {
             if targets.len() == 1 {
@@ -258,7 +258,7 @@ pub static PEN_CONVERSION: Elemental<ModType> = Elemental {
         prefix_name: "omni-sharp",
         roll_range: 0..=0,
         attune: Some(&attune::PEN_CONVERSION),
-        show_tooltip: |_this, ui, _roll| { tooltip!("%bleed penetration counts against all resistances"); },
+        tooltip: tooltip!("%bleed penetration counts against all resistances"),
         register: |hooks, _item, _equip, _roll| {
             hooks.on_pre_hit(move |attack, _skill, _user, _target| attack.pen_conversion.bleed = true);
         },
@@ -268,7 +268,7 @@ pub static PEN_CONVERSION: Elemental<ModType> = Elemental {
         prefix_name: "omni-grinding",
         roll_range: 0..=0,
         attune: Some(&attune::PEN_CONVERSION),
-        show_tooltip: |_this, ui, _roll| { tooltip!("%fracture penetration counts against all resistances"); },
+        tooltip: tooltip!("%fracture penetration counts against all resistances"),
         register: |hooks, _item, _equip, _roll| {
             hooks.on_pre_hit(move |attack, _skill, _user, _target| attack.pen_conversion.fracture = true);
         },
@@ -278,7 +278,7 @@ pub static PEN_CONVERSION: Elemental<ModType> = Elemental {
         prefix_name: "omni-disturbing",
         roll_range: 0..=0,
         attune: Some(&attune::PEN_CONVERSION),
-        show_tooltip: |_this, ui, _roll| { tooltip!("%madness penetration counts against all resistances"); },
+        tooltip: tooltip!("%madness penetration counts against all resistances"),
         register: |hooks, _item, _equip, _roll| {
             hooks.on_pre_hit(move |attack, _skill, _user, _target| attack.pen_conversion.madness = true);
         },
@@ -288,7 +288,7 @@ pub static PEN_CONVERSION: Elemental<ModType> = Elemental {
         prefix_name: "omni-silent", // TODO can do better hollow, vast eternal, perpetual
         roll_range: 0..=0,
         attune: Some(&attune::PEN_CONVERSION),
-        show_tooltip: |_this, ui, _roll| { tooltip!("%void penetration counts against all resistances"); },
+        tooltip: tooltip!("%void penetration counts against all resistances"),
         register: |hooks, _item, _equip, _roll| {
             hooks.on_pre_hit(move |attack, _skill, _user, _target| attack.pen_conversion.void = true);
         },
@@ -300,6 +300,68 @@ pub static LIGHT: ModType = ModType {
     prefix_name: "light",
     roll_range: 0..=0,
     attune: None,
-    show_tooltip: |_this, ui, _roll| { tooltip!("This Axe can be worn in the Shield slot"); },
+    tooltip: tooltip!("This Axe can be worn in the Shield slot"),
     register: |_hooks, _item, _equip, _roll| {}
-};
\ No newline at end of file
+};
+pub static CRIT_CHANCE: ModType = ModType {
+    id: 24,
+    prefix_name: "precise",
+    roll_range: 5..=15,
+    attune: None,
+    tooltip: tooltip!("%roll% %range chance to critically strike"),
+    register: |hooks, _item, _equip, roll| {
+        hooks.on_pre_hit(move |attack, _skill, _user, _target| attack.crit_chance += (roll as f32) / 100.);
+    },
+};
+pub static CRIT_MULTI: ModType = ModType {
+    id: 25,
+    prefix_name: "brutal",
+    roll_range: 25..=50,
+    attune: None,
+    tooltip: tooltip!("Critical strikes deal %roll% %range more damage"),
+    register: |hooks, _item, _equip, roll| {
+        hooks.on_pre_hit(move |attack, _skill, _user, _target| attack.crit_multi += (roll as f32) / 100.);
+    },
+};
+pub static DEBUFF_CHANCE: ModType = ModType {
+    id: 26,
+    prefix_name: "tormenting",
+    roll_range: 10..=25,
+    attune: None,
+    tooltip: tooltip!("%roll% %range chance to inflict the offensive Debuff of the primary damage type"),
+    register: |hooks, _item, _equip, roll| {
+        hooks.on_post_hit(move |attack, _skill, _user, _target, hit| {
+            if attack.rng.chance((roll as f32) / 100.) {
+                match hit.pre_res_dmg.max_idx() {
+                    Element::Bleed    => attack.debuffs.push(Debuff::bleed()),
+                    Element::Fracture => attack.debuffs.push(Debuff::vulnerable()),
+                    Element::Madness  => attack.debuffs.push(Debuff::confused()),
+                    Element::Void     => attack.debuffs.push(Debuff::echo(hit.pre_res_dmg)),
+                }
+            }
+        });
+    },
+};
+pub static DESPERATE: ModType = ModType {
+    id: 27,
+    prefix_name: "desperate",
+    roll_range: 5..=10,
+    attune: None,
+    tooltip: tooltip!("Deal %roll% %range more damage for every 10% of your max health that is wounded"),
+    register: |hooks, _item, _equip, roll| {
+        hooks.on_pre_hit(move |attack, _skill, user, _target| {
+            let wounded = user.wounds / user.stats().max_health;
+            attack.damage_mult = attack.damage_mult * (1. + wounded * 10. * (roll as f32) / 100.);
+        });
+    },
+};
+pub static HINDERING: ModType = ModType {
+    id: 28,
+    prefix_name: "hindering",
+    roll_range: 15..=30,
+    attune: None,
+    tooltip: tooltip!("Hits slow the cooldowns of the target by %roll% %range for 3 seconds"),
+    register: |hooks, _item, _equip, roll| {
+        hooks.on_post_hit(move |attack, _skill, _user, _target, _hit| attack.debuffs.push(Debuff::slow(roll as f32)));
+    },
+};
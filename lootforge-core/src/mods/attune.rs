@@ -21,6 +21,7 @@ pub enum AttuneKind {
     MatSpirit,
 }
 impl AttuneKind {
+    #[allow(clippy::len_without_is_empty)]
     pub const fn len(&self) -> usize {
         match self {
             AttuneKind::Element => 4,
@@ -1,5 +1,4 @@
 use super::ModType;
-use crate::elemental::Element;
 use crate::equipment::equipment::Equip;
 use crate::mods::attune;
 use crate::prelude::*;
@@ -9,9 +8,7 @@ pub static HEALTH: ModType = ModType {
     prefix_name: "stout",
     roll_range: 250..=500,
     attune: None,
-    show_tooltip: |this, ui, roll| {
-        tooltip!("Increase max health by %roll %range");
-    },
+    tooltip: tooltip!("Increase max health by %roll %range"),
     register: |hooks, _item, _equip, roll| {
         hooks.on_char(move |char| char.max_health += roll as f32);
     },
@@ -21,9 +18,7 @@ pub static MAT_RES: ModType = ModType {
     prefix_name: "heavy",
     roll_range: 15..=30,
     attune: Some(&attune::RES),
-    show_tooltip: |this, ui, roll| {
-        tooltip!("Increase Material (%bleed & %fracture) resistance by %roll %range");
-    },
+    tooltip: tooltip!("Increase Material (%bleed & %fracture) resistance by %roll %range"),
     register: |hooks, _item, _equip, roll| {
         hooks.on_char(move |char| {
             char.resistances.bleed += roll as f32;
@@ -36,9 +31,7 @@ pub static SPIRIT_RES: ModType = ModType {
     prefix_name: "engraved",
     roll_range: 15..=30,
     attune: Some(&attune::RES),
-    show_tooltip: |this, ui, roll| {
-        tooltip!("Increase Spiritual (%madness & %void) resistance by %roll %range");
-    },
+    tooltip: tooltip!("Increase Spiritual (%madness & %void) resistance by %roll %range"),
     register: |hooks, _item, _equip, roll| {
         hooks.on_char(move |char| {
             char.resistances.madness += roll as f32;
@@ -51,9 +44,7 @@ pub static CDR: ModType = ModType {
     prefix_name: "quick",
     roll_range: 0..=0,
     attune: None,
-    show_tooltip: |_this, ui, _roll| {
-        tooltip!("Skills cooldown is reduce by 10%");
-    },
+    tooltip: tooltip!("Skills cooldown is reduce by 10%"),
     register: |hooks, _item, _equip, _roll| {
         hooks.on_char(move |char| char.cdr += 10);
     },
@@ -63,9 +54,7 @@ pub static HEAL_POWER: ModType = ModType {
     prefix_name: "vitalising",
     roll_range: 20..=30,
     attune: None,
-    show_tooltip: |this, ui, roll| {
-        tooltip!("Increase heal power by %roll% %range");
-    },
+    tooltip: tooltip!("Increase heal power by %roll% %range"),
     register: |hooks, _item, _equip, roll| {
         hooks.on_char(move |char| char.heal_power += percent(roll));
     },
@@ -75,9 +64,7 @@ pub static SHIELD_POWER: ModType = ModType {
     prefix_name: "aegised",
     roll_range: 20..=50,
     attune: None,
-    show_tooltip: |this, ui, roll| {
-        tooltip!("Increase shield power by %roll% %range");
-    },
+    tooltip: tooltip!("Increase shield power by %roll% %range"),
     register: |hooks, _item, _equip, roll| {
         hooks.on_char(move |char| char.shield_power += percent(roll));
     },
@@ -87,9 +74,7 @@ pub static SHIELD_START: ModType = ModType {
     prefix_name: "bulwark",
     roll_range: 30..=50,
     attune: None,
-    show_tooltip: |this, ui, roll| {
-        tooltip!("Start combat with %roll% %range of your max health as shield");
-    },
+    tooltip: tooltip!("Start combat with %roll% %range of your max health as shield"),
     register: |hooks, _item, _equip, roll| {
         hooks.on_combat_start(move |effects, _char| effects.shield_from_max_health += percent(roll));
     },
@@ -99,9 +84,7 @@ pub static HEALTH_EX: ModType = ModType {
     prefix_name: "immortal",
     roll_range: 0..=0,
     attune: None,
-    show_tooltip: |_this, ui, _roll| {
-        tooltip!("Increase max health by 1234, if no other items give max health");
-    },
+    tooltip: tooltip!("Increase max health by 1234, if no other items give max health"),
     register: |hooks, item, equip, _roll| {
         let has_health = equip.iter()
             .filter_map(|i| i.upgrade())
@@ -113,6 +96,56 @@ pub static HEALTH_EX: ModType = ModType {
         }
     },
 };
+pub static EVASION: ModType = ModType {
+    id: 58,
+    prefix_name: "elusive",
+    roll_range: 5..=10,
+    attune: None,
+    tooltip: tooltip!("%roll% %range chance to evade attacks"),
+    register: |hooks, _item, _equip, roll| {
+        hooks.on_char(move |char| char.evasion += percent(roll));
+    },
+};
+pub static MENDING: ModType = ModType {
+    id: 59,
+    prefix_name: "mending",
+    roll_range: 25..=50,
+    attune: None,
+    tooltip: tooltip!("Mend an additional %roll% %range of your wounds when entering a new floor"),
+    register: |hooks, _item, _equip, roll| {
+        hooks.on_char(move |char| char.wound_mending += percent(roll));
+    },
+};
+pub static RESTING: ModType = ModType {
+    id: 60,
+    prefix_name: "resting",
+    roll_range: 5..=15,
+    attune: None,
+    tooltip: tooltip!("Recover an additional %roll% %range of your max health between battles"),
+    register: |hooks, _item, _equip, roll| {
+        hooks.on_char(move |char| char.recovery += percent(roll));
+    },
+};
+pub static HARDENED: ModType = ModType {
+    id: 61,
+    prefix_name: "hardened",
+    roll_range: 20..=40,
+    attune: None,
+    tooltip: tooltip!("%roll% %range less of the damage you take turns into wounds"),
+    register: |hooks, _item, _equip, roll| {
+        hooks.on_char(move |char| char.wound_conversion *= 1. - percent(roll));
+    },
+};
+pub static SWIFT: ModType = ModType {
+    id: 62,
+    prefix_name: "swift",
+    roll_range: 5..=15,
+    attune: None,
+    tooltip: tooltip!("Skills recover their cooldown %roll% %range faster"),
+    register: |hooks, _item, _equip, roll| {
+        hooks.on_char(move |char| char.speed += percent(roll));
+    },
+};
 
 fn percent(roll: u16) -> f32 {
     (roll as f32) / 100.
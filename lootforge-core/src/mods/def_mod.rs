@@ -9,7 +9,7 @@ pub static SHIELD: ModType = ModType {
     prefix_name: "shielding",
     roll_range: 20..=50,
     attune: None,
-    show_tooltip: |this, ui, roll| { tooltip!("Gain %roll %range shield"); },
+    tooltip: tooltip!("Gain %roll %range shield"),
     register: |hooks, _item, _equip, roll| {
         hooks.on_defend(move |def, _skill, _user| def.shield += roll as f32);
     },    
@@ -20,7 +20,7 @@ pub static HEAL: ModType = ModType {
     prefix_name: "healing",
     roll_range: 15..=30,
     attune: None,
-    show_tooltip: |this, ui, roll| { tooltip!("Heal %roll %range Hitpoints"); },
+    tooltip: tooltip!("Heal %roll %range Hitpoints"),
     register: |hooks, _item, _equip, roll| {
         hooks.on_defend(move |def, _skill, _user| def.heal += roll as f32,);
     },    
@@ -31,7 +31,7 @@ pub static BLOCK: ModType = ModType {
     prefix_name: "blocking",
     roll_range: 0..=0,
     attune: None,
-    show_tooltip: |_this, ui, _roll| { tooltip!("Completely Negate the attack"); },
+    tooltip: tooltip!("Completely Negate the attack"),
     register: |hooks, _item, _equip, _roll| {
         hooks.on_resp_pre_atk(move |resp, _user, _skill, _attacker| resp.block = true);
     },
@@ -44,7 +44,7 @@ pub static COUNTER: ModType = ModType {
     prefix_name: "counter",
     roll_range: 0..=0,
     attune: None,
-    show_tooltip: |_this, ui, _roll| { tooltip!("Trigger a counter attack against the attacker"); },
+    tooltip: tooltip!("Trigger a counter attack against the attacker"),
     register: |hooks, _item, _equip, _roll| {
         hooks.on_resp_post_atk(move |resp, _skill, _user, _attacker, _hit| resp.counter = true);
     },
@@ -56,7 +56,7 @@ pub static ATTUNE: ModType = ModType {
     prefix_name: "attuning",
     roll_range: 3..=6,
     attune: None,
-    show_tooltip: |this, ui, roll| { tooltip!("Grants a stackable Buff that gives %roll %range resistance against the attacks primary damage type"); },
+    tooltip: tooltip!("Grants a stackable Buff that gives %roll %range resistance against the attacks primary damage type"),
     register: |hooks, _item, _equip, roll| {
         hooks.on_resp_post_atk(move |resp, _skill, _user, _attacker, hit| resp.buffs.push(Buff::attuned(roll as f32, hit.post_res_dmg.max_idx())));
     },
@@ -66,7 +66,7 @@ pub static REVERB: ModType = ModType {
     prefix_name: "reverberant",
     roll_range: 0..=0,
     attune: None,
-    show_tooltip: |_this, ui, _roll| { tooltip!("Grants a Buff that will add the taken Damage to your next attack"); },
+    tooltip: tooltip!("Grants a Buff that will add the taken Damage to your next attack"),
     register: |hooks, _item, _equip, _roll| {
         hooks.on_resp_post_atk(move |resp, _skill, _user, _attacker, hit| resp.buffs.push(Buff::reverb(hit.pre_res_dmg)));
     },
@@ -76,10 +76,42 @@ pub static DEF_READY: ModType = ModType {
     prefix_name: "braced",
     roll_range: 0..=0,
     attune: None,
-    show_tooltip: |_this, ui, _roll| { tooltip!("Start Combat with your defensive skill ready"); },
+    tooltip: tooltip!("Start Combat with your defensive skill ready"),
     register: |hooks, _item, equip, _roll| {
         if let Some(helmet_id) = equip.get_item(ItemSlot::Helmet).0.upgrade().map(|h| h.id) {
             hooks.on_combat_start(move |effects, _user| effects.ready_skills.push(helmet_id));
         }
     },
-};
\ No newline at end of file
+};
+
+pub static EMPOWER: ModType = ModType {
+    id: 107,
+    prefix_name: "empowering",
+    roll_range: 15..=30,
+    attune: None,
+    tooltip: tooltip!("Grants a Buff that increases the damage of the next attack by %roll% %range"),
+    register: |hooks, _item, _equip, roll| {
+        hooks.on_defend(move |def, _skill, _user| def.buffs.push(Buff::empowered(roll as f32)));
+    },
+};
+// on a shield this taunts with every defensive skill of its owner
+pub static TAUNT: ModType = ModType {
+    id: 108,
+    prefix_name: "taunting",
+    roll_range: 50..=100,
+    attune: None,
+    tooltip: tooltip!("Generate %roll %range threat with all enemies"),
+    register: |hooks, _item, _equip, roll| {
+        hooks.on_defend(move |def, _skill, _user| def.taunt += roll as f32);
+    },
+};
+pub static HASTENING: ModType = ModType {
+    id: 109,
+    prefix_name: "hastening",
+    roll_range: 10..=25,
+    attune: None,
+    tooltip: tooltip!("Grants a Buff that makes cooldowns recover %roll% %range faster for 5 seconds"),
+    register: |hooks, _item, _equip, roll| {
+        hooks.on_defend(move |def, _skill, _user| def.buffs.push(Buff::haste(roll as f32)));
+    },
+};
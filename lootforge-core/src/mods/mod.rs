@@ -1,6 +1,6 @@
 use roll_tables::ALL_MODS;
 
-use crate::{combat::hooks::CombatHooks, equipment::equipment::EquipEnum, item::item::Item, mods::attune::AttuneGroup, panels::forge::forge, prelude::*};
+use crate::{combat::hooks::CombatHooks, elemental::Element, equipment::equipment::EquipEnum, item::item::Item, mods::attune::AttuneGroup, prelude::*};
 
 pub mod atk_mod;
 pub mod attune;
@@ -18,17 +18,8 @@ impl RolledMod {
     pub fn mod_type(&self) -> &'static ModType {
         ALL_MODS[&self.mod_id]
     }
-    pub fn show_tooltip(&self, ui: &mut Ui) {
-        // TODO change default color to a slightly brighter grey
-        // TODO check how poe and d3/4 show mods
-        let mod_type = self.mod_type();
-        if mod_type.attune.is_some() {
-            ui.add(forge::Tab::Attune.image().fit_to_exact_size(vec2(16., 16.)));
-        }
-        (mod_type.show_tooltip)(&self.mod_type(), ui, self.roll);
-    }
     pub fn register(&self, hooks: &mut CombatHooks, item: &Item, equip: &EquipEnum) {
-        (self.mod_type().register)(hooks, item, equip, self.roll);
+        hooks.register_mod(self.mod_id, |hooks| (self.mod_type().register)(hooks, item, equip, self.roll));
     }
 }
 
@@ -46,11 +37,20 @@ pub struct ModType {
     pub roll_range: RangeInclusive<u16>,
     pub attune: Option<&'static AttuneGroup>,
 
-    pub show_tooltip: fn(&Self, &mut Ui, u16), // potentially, we can do this as a string with placeholder and style info... but lets get it decent for a good number of mods before we begin that
+    pub tooltip: &'static [TooltipPart],
     // longer description for book
     pub register: fn(hooks: &mut CombatHooks, item: &Item, equip: &EquipEnum, roll: u16),
 }
 
+// built by the tooltip! macro, "%roll", "%range" and "%bleed" etc. become their own parts
+#[derive(Debug, Clone, Copy)]
+pub enum TooltipPart {
+    Text(&'static str),
+    Roll,
+    Range,
+    Element(Element),
+}
+
 impl PartialEq for ModType {
     fn eq(&self, other: &Self) -> bool {
         ptr::eq(self, other)
@@ -75,6 +75,11 @@ impl ModType {
         }
     }
 
+    // whether the mod is about the element, like added damage or penetration of it
+    pub fn mentions(&self, element: Element) -> bool {
+        self.tooltip.iter().any(|p| matches!(p, TooltipPart::Element(e) if *e == element))
+    }
+
     pub fn attunement(&'static self) -> Option<(&'static AttuneGroup, usize)> {
         // TODO this should be pre computable at compile time, but it's weird because then mod and group depend on each other
         self.attune.map(|group| (group, group.idx(self).unwrap()) )
@@ -17,7 +17,7 @@ pub static ALL_MODS: LazyLock<BTreeMap<u16, &'static ModType>> = LazyLock::new(|
     }
 
     let duplicates: Vec<_> = mods.iter().duplicates_by(|m| m.id).collect();
-    if duplicates.len() > 0 {
+    if !duplicates.is_empty() {
         panic!("Mod Ids have Duplicates : {:?},", duplicates)
     }
 
@@ -28,6 +28,14 @@ static ALL_ROLL_TABLES: RollTable = RollTable::new(
     &[
         Table(&AXE_ROLL_TABLE),
         Table(&SWORD_ROLL_TABLE),
+        Table(&CROSSBOW_ROLL_TABLE),
+        Table(&BOW_ROLL_TABLE),
+        Table(&QUIVER_ROLL_TABLE),
+        Table(&SATCHEL_ROLL_TABLE),
+        Table(&ATTACK_GEM_ROLL_TABLE),
+        Table(&AOE_GEM_ROLL_TABLE),
+        Table(&SUPPORT_GEM_ROLL_TABLE),
+        Table(&STAFF_ROLL_TABLE),
         Table(&GLOVE_ROLL_TABLE),
         Table(&HELMET_ROLL_TABLE),
         Table(&ARMOR_ROLL_TABLE),
@@ -52,6 +60,56 @@ pub static SWORD_ROLL_TABLE: RollTable = RollTable::new(
     &[],
 );
 
+pub static CROSSBOW_ROLL_TABLE: RollTable = RollTable::new(
+    &[
+        Table(&SINGLE_TARGET_WEAPON_ROLL_TABLE),
+    ],
+    &[],
+);
+pub static BOW_ROLL_TABLE: RollTable = RollTable::new(
+    &[
+        Table(&AOE_WEAPON_ROLL_TABLE),
+    ],
+    &[],
+);
+pub static QUIVER_ROLL_TABLE: RollTable = RollTable::new(
+    &[
+        Table(&OFFENSIVE_ROLL_TABLE),
+        Mod(&CULLING, 0.8, true),
+    ],
+    &[],
+);
+pub static SATCHEL_ROLL_TABLE: RollTable = RollTable::new(
+    &[
+        Table(&SUPPORT_ROLL_TABLE),
+    ],
+    &[],
+);
+pub static ATTACK_GEM_ROLL_TABLE: RollTable = RollTable::new(
+    &[
+        Table(&SINGLE_TARGET_WEAPON_ROLL_TABLE),
+    ],
+    &[],
+);
+pub static AOE_GEM_ROLL_TABLE: RollTable = RollTable::new(
+    &[
+        Table(&AOE_WEAPON_ROLL_TABLE),
+    ],
+    &[],
+);
+pub static SUPPORT_GEM_ROLL_TABLE: RollTable = RollTable::new(
+    &[
+        Table(&SUPPORT_ROLL_TABLE),
+    ],
+    &[],
+);
+pub static STAFF_ROLL_TABLE: RollTable = RollTable::new(
+    &[
+        Table(&SUPPORT_ROLL_TABLE),
+    ],
+    &[],
+);
+
 pub static GLOVE_ROLL_TABLE: RollTable = RollTable::new(
     &[
         Table(&OFFENSIVE_ROLL_TABLE),
@@ -86,6 +144,7 @@ pub static ARMOR_ROLL_TABLE: RollTable = RollTable::new(
 pub static SHIELD_ROLL_TABLE: RollTable = RollTable::new(
     &[
         Table(&CHAR_ROLL_TABLE),
+        Mod(&TAUNT, 1., true),
     ],
     &[],
 );
@@ -122,6 +181,8 @@ static WEAPON_ROLL_TABLE: RollTable = RollTable::new(
         Table(&OFFENSIVE_ROLL_TABLE),
         Mod(&CULLING, 0.8, true),
         Mod(&CDR, 1., true),
+        Mod(&DEBUFF_CHANCE, 0.5, true),
+        Mod(&HINDERING, 0.5, true),
     ],
     &[],
 );
@@ -130,6 +191,9 @@ static OFFENSIVE_ROLL_TABLE: RollTable = RollTable::new(
     &[
         EMod(&ADDED_DMG, 2., false),
         EMod(&PENETRATION, 1., false),
+        Mod(&CRIT_CHANCE, 1., false),
+        Mod(&CRIT_MULTI, 0.5, false),
+        Mod(&DESPERATE, 0.5, true),
     ],
     &[],
 );
@@ -141,6 +205,7 @@ static DEFENSIVE_ROLL_TABLE: RollTable = RollTable::new(
         Mod(&COUNTER, 1., true),
         Mod(&ATTUNE, 1.5, true),
         Mod(&REVERB, 1.5, true),
+        Mod(&TAUNT, 1., true),
     ],
     &[
         &[&BLOCK, &COUNTER],
@@ -148,6 +213,16 @@ static DEFENSIVE_ROLL_TABLE: RollTable = RollTable::new(
         &[&BLOCK, &REVERB],
     ],
 );
+static SUPPORT_ROLL_TABLE: RollTable = RollTable::new(
+    &[
+        Mod(&SHIELD, 2., false),
+        Mod(&HEAL, 2., false),
+        Mod(&EMPOWER, 2., false),
+        Mod(&HASTENING, 1., true),
+        Mod(&CDR, 1., true),
+    ],
+    &[],
+);
 static CHAR_ROLL_TABLE: RollTable = RollTable::new(
     &[
         Mod(&HEALTH, 4., false),
@@ -155,6 +230,11 @@ static CHAR_ROLL_TABLE: RollTable = RollTable::new(
         Mod(&SPIRIT_RES, 2., false),
         Mod(&HEAL_POWER, 2., false),
         Mod(&SHIELD_POWER, 2., false),
+        Mod(&EVASION, 1., true),
+        Mod(&MENDING, 1., true),
+        Mod(&RESTING, 1., true),
+        Mod(&HARDENED, 1., true),
+        Mod(&SWIFT, 1., true),
     ],
     &[],
 );
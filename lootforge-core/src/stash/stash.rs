@@ -57,7 +57,7 @@ impl Stash {
         mem::drop(item); // invalidate the rc, so that the only remaining one is the stashes
 
         let item = self.items.iter_mut().find(|item| item.id == id).unwrap();
-        let count = Rc::strong_count(&item);
+        let count = Rc::strong_count(item);
         if count != 1 {
             panic!("Attempting to modify item {}, but count is {}", item.id, count)
         }
@@ -75,7 +75,7 @@ impl Stash {
         self.items
             .iter()
             .find(|item| item.id == item_id)
-            .map(|item| item.clone())
+            .cloned()
     }
 
     pub fn items(&self) -> &Vec<Rc<Item>> {
@@ -90,7 +90,7 @@ impl Stash {
 
         let filtered = Rc::new(self.items.iter()
             .filter(|item| filter.filter(item))
-            .map(|item| item.clone())
+            .cloned()
             .sorted_by(|a, b| order.cmp(a, b))
             .collect::<Vec<_>>());
 
@@ -0,0 +1,3 @@
+pub mod ser;
+pub mod ser_v1;
+pub mod ser_v2;
@@ -1,4 +1,4 @@
-use super::ser_v1;
+use super::{ser_v1, ser_v2};
 
 pub const MAGIC: [u8;8] = [b'l', b'o', b'o', b't', b'\xF0', b'\x01', b'\xFF', b'\n'];
 
@@ -9,12 +9,13 @@ pub struct ValidatedBytes {
 }
 impl ValidatedBytes {
     pub fn validate(bytes: Vec<u8>) -> Option<ValidatedBytes> {
-        if bytes.len() < 9 || &bytes[0..8] != MAGIC {
+        if bytes.len() < 9 || bytes[0..8] != MAGIC {
             return None
         }
         let version = bytes[8];
         let ts = match version {
             1 => ser_v1::validate(&bytes)?,
+            2 => ser_v2::validate(&bytes)?,
             _ => panic!("unknown file version: {}", version)
         };
 
@@ -0,0 +1,108 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    dungeon::{dungeon_data::{DungeonData, DungeonRun}, reward::{ChestSource, RewardChest}, stop::StopReason},
+    prelude::*,
+};
+
+use super::ser_v2::{deser_bytes, deser_dungeon_item, deser_item, deser_stash, deser_u16, deser_u32, deser_u64, deser_u8, deser_wardrobe};
+
+// v1 saves are only read, they are written as v2 from then on.
+// header, stash, wardrobe and items did not change, only the dungeon part did
+pub use super::ser_v2::{validate, SaveData};
+
+pub fn deser(mut bytes: &[u8]) -> Option<SaveData> {
+    let epoch_millis = validate(bytes)?;
+    bytes = &bytes[17..bytes.len() - 4];
+
+    let stash = deser_stash(&mut bytes)?;
+    let wardrobe = deser_wardrobe(&mut bytes, &stash)?;
+    let dungeon_data = deser_dungeon_data(&mut bytes, wardrobe.equipped)?;
+    if !bytes.is_empty() {
+        error!("{} bytes left over after reading the save", bytes.len());
+        return None;
+    }
+
+    Some(SaveData {
+        epoch_millis,
+        stash,
+        wardrobe,
+        dungeon: dungeon_data,
+    })
+}
+
+fn deser_rewards(bytes: &mut &[u8]) -> Option<BTreeMap<u16, Vec<RewardChest>>> {
+    let mut rewards: BTreeMap<u16, Vec<RewardChest>> = BTreeMap::new();
+    let len = deser_u32(bytes)?;
+    for _ in 0..len {
+        let depth = deser_u16(bytes)?;
+        let len = deser_u16(bytes)?;
+        let items = (0..len).map(|_| deser_item(bytes, false)).collect::<Option<_>>()?;
+        // boss chests were not told apart back then
+        rewards.entry(depth).or_default().push(RewardChest { depth, items, wardrobe: 0, source: ChestSource::End });
+    }
+    Some(rewards)
+}
+
+// there was only the one run, fighting with whatever set was equipped
+fn deser_dungeon_data(bytes: &mut &[u8], equipped: usize) -> Option<DungeonData> {
+    let dropped = deser_dungeon(bytes)?;
+    let rewards = deser_rewards(bytes)?;
+    let auto_restart = deser_u8(bytes)? != 0;
+    Some(DungeonData {
+        runs: vec![DungeonRun {
+            wardrobe: equipped,
+            auto_restart,
+            last_stop: dropped.then_some(StopReason::Outdated),
+            ..Default::default()
+        }],
+        selected: 0,
+        rewards,
+    })
+}
+
+// the simulation changed since v1, so a run that was in progress can't be simulated back to where it was.
+// it is read past and dropped, auto restart picks up from there. v1 did not store the depth, so there is no telling how far it got
+fn deser_dungeon(bytes: &mut &[u8]) -> Option<bool> {
+    let finished = deser_u8(bytes)? != 0;
+    if finished {
+        return Some(false);
+    }
+
+    // the fighter items, ranger and mage did not exist yet and had their slots written as zeros
+    let mut items = Vec::new();
+    for _ in 0..9 {
+        deser_dungeon_item(bytes, &mut items)?;
+    }
+    for _ in 0..18 {
+        deser_u32(bytes)?;
+    }
+    let tick = deser_u64(bytes)?;
+    deser_bytes::<32>(bytes)?;
+    deser_u32(bytes)?;
+
+    warn!("Dropped a v1 Dungeon that was {} ticks in", tick);
+    Some(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the last v1 build saved this: 4 items, a fighter in set 2, 12 chests and auto restart on, with a run 1207 ticks in
+    const IN_PROGRESS: &[u8] = include_bytes!("v1_in_progress.save");
+
+    #[test]
+    fn everything_but_the_run_in_progress_is_kept() {
+        let save = deser(IN_PROGRESS).unwrap();
+        assert_eq!(save.stash.items().len(), 4);
+        assert_eq!(save.wardrobe.equipped, 2);
+        assert_eq!(save.dungeon.rewards.values().flatten().count(), 12);
+
+        let run = save.dungeon.selected();
+        assert_eq!(run.wardrobe, 2);
+        assert!(run.auto_restart);
+        assert!(run.cur.finished);
+        assert_eq!(run.last_stop, Some(StopReason::Outdated));
+    }
+}
@@ -0,0 +1,643 @@
+use std::{
+    collections::BTreeMap,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use enumset::EnumSet;
+
+use crate::{
+    combat::{buff::BuffIcon, skill::targeting::Targeting}, dungeon::{dungeon::{Background, Dungeon}, dungeon_data::{DungeonData, DungeonRun}, floor::{Floor, RoutePolicy}, replay::RunRecord, reward::{ChestSource, RewardChest}, stop::{EarlyDeaths, StopReason, StopRules}}, elemental::Element, equipment::{
+        equipment::{CommonEquip, FighterEquip, MageEquip, RangerEquip},
+        wardrobe::{EquipmentSet, OwningEquipmentSet, Wardrobe},
+    }, item::{item::{Item, ItemRef}, item_type::ItemType, tags::{ItemTags, Rating}}, mods::RolledMod, prelude::*, stash::stash::Stash
+};
+
+use super::ser;
+
+const CRC: crc::Crc<u32> = crc::Crc::<u32>::new(&crc::CRC_32_ISO_HDLC);
+
+pub fn validate(bytes: &[u8]) -> Option<u64> {
+    if bytes.len() < 1000 {
+        return None;
+    }
+    let check_sum = CRC.checksum(&bytes[..bytes.len() - 4]).to_le_bytes();
+    if bytes[bytes.len() - 4..] != check_sum {
+        return None;
+    }
+
+    Some(u64::from_le_bytes(bytes[9..17].try_into().ok()?))
+}
+
+// TODO if I use a trait, I don't have to make everything pub :)
+
+pub struct SaveData {
+    pub epoch_millis: u64,
+    pub stash: Stash,
+    pub wardrobe: Wardrobe,
+    pub dungeon: DungeonData,
+}
+
+pub fn ser(stash: &Stash, wardrobe: &Wardrobe, dungeon: &DungeonData, epoch_millis: u64) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(1000);
+
+    bytes.extend_from_slice(&ser::MAGIC);
+    ser_u8(&mut bytes, 2);
+    ser_u64(&mut bytes, epoch_millis);
+    let items = ser_stash(&mut bytes, stash);
+    ser_wardrobe(&mut bytes, wardrobe, &items);
+    ser_dungeon_data(&mut bytes, dungeon);
+
+    let check_sum = CRC.checksum(&bytes).to_le_bytes();
+    bytes.extend_from_slice(&check_sum);
+    bytes
+}
+
+pub fn deser(mut bytes: &[u8]) -> Option<SaveData> {
+    let epoch_millis = validate(bytes)?;
+    bytes = &bytes[17..bytes.len() - 4];
+
+    let stash = deser_stash(&mut bytes)?;
+    let wardrobe = deser_wardrobe(&mut bytes, &stash)?;
+    let dungeon_data = deser_dungeon_data(&mut bytes)?;
+    if !bytes.is_empty() {
+        error!("{} bytes left over after reading the save", bytes.len());
+        return None;
+    }
+
+    Some(SaveData {
+        epoch_millis,
+        stash,
+        wardrobe,
+        dungeon: dungeon_data,
+    })
+}
+
+fn ser_stash(bytes: &mut Vec<u8>, stash: &Stash) -> BTreeMap<usize, u32> {
+    let mut items = BTreeMap::new();
+
+    ser_u32(bytes, stash.items().len() as u32);
+    for (i, item) in stash.items().iter().enumerate() {
+        ser_item(bytes, item.as_ref(), true);
+        if item.tags.any_wardrobe() {
+            items.insert(item.id, (i + 1) as u32);
+        }
+    }
+    items
+}
+pub(super) fn deser_stash(bytes: &mut &[u8]) -> Option<Stash> {
+    let mut stash = Stash::default();
+    let len = deser_u32(bytes)?;
+    for _ in 0..len {
+        stash.add(deser_item(bytes, true)?);
+    }
+    Some(stash)
+}
+
+fn ser_wardrobe(bytes: &mut Vec<u8>, wardrobe: &Wardrobe, items: &BTreeMap<usize, u32>) {
+    let ser_slot = |bytes: &mut Vec<u8>, slot: &Weak<Item>| {
+        if let Some(item) = slot.upgrade() {
+            ser_u32(bytes, items[&item.id]);
+        } else {
+            ser_u32(bytes, 0);
+        }
+    };
+
+    ser_u8(bytes, wardrobe.equipped as u8);
+    for set in &wardrobe.sets {
+        ser_equipment_set(bytes, set, ser_slot);
+    }
+}
+pub(super) fn deser_wardrobe(bytes: &mut &[u8], stash: &Stash) -> Option<Wardrobe> {
+    let deser_slot = |bytes: &mut &[u8]| {
+        let id = deser_u32(bytes)? as usize;
+        if id == 0 {
+            Some(Weak::new())
+        } else {
+            Some(Rc::downgrade(&stash.find(id)?))
+        }
+    };
+
+    let equipped = deser_u8(bytes)? as usize;
+    // TODO maybe use https://doc.rust-lang.org/stable/std/array/fn.try_from_fn.html if it becomes stable
+    let sets = [
+        deser_equipment_set(bytes, deser_slot)?,
+        deser_equipment_set(bytes, deser_slot)?,
+        deser_equipment_set(bytes, deser_slot)?,
+        deser_equipment_set(bytes, deser_slot)?,
+        deser_equipment_set(bytes, deser_slot)?,
+        deser_equipment_set(bytes, deser_slot)?,
+        deser_equipment_set(bytes, deser_slot)?,
+        deser_equipment_set(bytes, deser_slot)?,
+        deser_equipment_set(bytes, deser_slot)?,
+    ];
+
+    sets.iter().enumerate().for_each(|(i, set)| {
+        set.iter().for_each(|item| {
+            if let Some(item) = item.upgrade() {
+                item.tags.add_wardrobe(i);
+            }
+        });
+    });
+
+    let mut wardrobe = Wardrobe { sets, equipped: (equipped + 1) % 9 };
+    wardrobe.set_equipped(equipped); // TODO this sets the equipped flag, prolly can do that a bit nicer
+    Some(wardrobe)
+}
+
+fn ser_equipment_set(bytes: &mut Vec<u8>, set: &EquipmentSet, ser_slot: impl Fn(&mut Vec<u8>, &ItemRef)) {
+    ser_slot(bytes, &set.fighter_equip.weapons[0]);
+    ser_slot(bytes, &set.fighter_equip.weapons[1]);
+    ser_slot(bytes, &set.fighter_equip.shield);
+    ser_slot(bytes, &set.fighter_equip.common.helmet);
+    ser_slot(bytes, &set.fighter_equip.common.armor);
+    ser_slot(bytes, &set.fighter_equip.common.gloves);
+    ser_slot(bytes, &set.fighter_equip.common.rings[0]);
+    ser_slot(bytes, &set.fighter_equip.common.rings[1]);
+    ser_slot(bytes, &set.fighter_equip.common.rings[2]);
+
+    ser_slot(bytes, &set.ranger_equip.weapons[0]);
+    ser_slot(bytes, &set.ranger_equip.weapons[1]);
+    ser_slot(bytes, &set.ranger_equip.quiver);
+    ser_slot(bytes, &set.ranger_equip.satchel);
+    ser_slot(bytes, &set.ranger_equip.common.helmet);
+    ser_slot(bytes, &set.ranger_equip.common.armor);
+    ser_slot(bytes, &set.ranger_equip.common.gloves);
+    ser_slot(bytes, &set.ranger_equip.common.rings[0]);
+    ser_slot(bytes, &set.ranger_equip.common.rings[1]);
+
+    ser_slot(bytes, &set.mage_equip.weapons[0]);
+    ser_slot(bytes, &set.mage_equip.weapons[1]);
+    ser_slot(bytes, &set.mage_equip.support_gem);
+    ser_slot(bytes, &set.mage_equip.staff);
+    ser_slot(bytes, &set.mage_equip.common.helmet);
+    ser_slot(bytes, &set.mage_equip.common.armor);
+    ser_slot(bytes, &set.mage_equip.common.gloves);
+    ser_slot(bytes, &set.mage_equip.common.rings[0]);
+    ser_slot(bytes, &set.mage_equip.common.rings[1]);
+}
+fn deser_equipment_set(bytes: &mut &[u8], mut deser_slot: impl FnMut(&mut &[u8]) -> Option<ItemRef>) -> Option<EquipmentSet> {
+    let set = EquipmentSet {
+        fighter_equip: FighterEquip {
+            weapons: [deser_slot(bytes)?, deser_slot(bytes)?],
+            shield: deser_slot(bytes)?,
+            common: CommonEquip {
+                helmet: deser_slot(bytes)?,
+                armor: deser_slot(bytes)?,
+                gloves: deser_slot(bytes)?,
+                rings: [deser_slot(bytes)?, deser_slot(bytes)?, deser_slot(bytes)?],
+            },
+        },
+        ranger_equip: RangerEquip {
+            weapons: [deser_slot(bytes)?, deser_slot(bytes)?],
+            quiver: deser_slot(bytes)?,
+            satchel: deser_slot(bytes)?,
+            common: CommonEquip {
+                helmet: deser_slot(bytes)?,
+                armor: deser_slot(bytes)?,
+                gloves: deser_slot(bytes)?,
+                rings: [deser_slot(bytes)?, deser_slot(bytes)?],
+            },
+        },
+        mage_equip: MageEquip {
+            weapons: [deser_slot(bytes)?, deser_slot(bytes)?],
+            support_gem: deser_slot(bytes)?,
+            staff: deser_slot(bytes)?,
+            common: CommonEquip {
+                helmet: deser_slot(bytes)?,
+                armor: deser_slot(bytes)?,
+                gloves: deser_slot(bytes)?,
+                rings: [deser_slot(bytes)?, deser_slot(bytes)?],
+            },
+        },
+    };
+    Some(set)
+}
+
+fn ser_rewards(bytes: &mut Vec<u8>, rewards: &BTreeMap<u16, Vec<RewardChest>>) {
+    let rewards_len: usize = rewards.values().map(|r| r.len()).sum();
+    ser_u32(bytes, rewards_len as u32);
+    for chest in rewards.values().flatten() {
+        ser_u16(bytes, chest.depth);
+        ser_u8(bytes, chest.wardrobe as u8);
+        ser_u8(bytes, chest.source as u8);
+        ser_u16(bytes, chest.items.len() as u16);
+        for item in &chest.items {
+            ser_item(bytes, item, false);
+        }
+    }
+}
+fn deser_rewards(bytes: &mut &[u8]) -> Option<BTreeMap<u16, Vec<RewardChest>>> {    
+    let mut rewards: BTreeMap<u16, Vec<RewardChest>> = BTreeMap::new();
+    let len = deser_u32(bytes)?;
+    for _ in 0..len {
+        let depth = deser_u16(bytes)?;
+        let wardrobe = deser_u8(bytes)? as usize;
+        let source = ChestSource::from_repr(deser_u8(bytes)? as usize)?;
+        let len = deser_u16(bytes)?;
+        let items = (0..len).map(|_| deser_item(bytes, false)).collect::<Option<_>>()?;
+        rewards.entry(depth).or_default().push(RewardChest { depth, items, wardrobe, source });
+    }
+    Some(rewards)
+}
+
+fn ser_dungeon_data(bytes: &mut Vec<u8>, dungeon_data: &DungeonData) {
+    ser_u8(bytes, dungeon_data.runs.len() as u8);
+    for run in &dungeon_data.runs {
+        ser_dungeon_run(bytes, run);
+    }
+    ser_rewards(bytes, &dungeon_data.rewards);
+}
+fn deser_dungeon_data(bytes: &mut &[u8]) -> Option<DungeonData> {
+    let len = deser_u8(bytes)?;
+    let runs: Vec<_> = (0..len).map(|_| deser_dungeon_run(bytes)).collect::<Option<_>>()?;
+    if runs.is_empty() {
+        return None;
+    }
+    Some(DungeonData {
+        runs,
+        selected: 0,
+        rewards: deser_rewards(bytes)?,
+    })
+}
+
+fn ser_dungeon_run(bytes: &mut Vec<u8>, run: &DungeonRun) {
+    ser_u8(bytes, run.wardrobe as u8);
+    ser_dungeon(bytes, &run.cur);
+    ser_u8(bytes, run.auto_restart as u8);
+    ser_u8(bytes, run.area as u8);
+    ser_u8(bytes, run.route_policy as u8);
+    ser_u8(bytes, run.modifiers.as_u8());
+    ser_stop_rules(bytes, &run.stop_rules);
+    ser_u8(bytes, run.last_stop.map_or(0, |reason| reason as u8 + 1));
+    ser_u16(bytes, run.early_deaths);
+    ser_u8(bytes, run.history.len() as u8);
+    for record in &run.history {
+        ser_run_record(bytes, record);
+    }
+}
+fn deser_dungeon_run(bytes: &mut &[u8]) -> Option<DungeonRun> {
+    Some(DungeonRun {
+        wardrobe: Some(deser_u8(bytes)? as usize).filter(|w| *w < 9)?,
+        cur: deser_dungeon(bytes)?,
+        auto_restart: deser_u8(bytes)? != 0,
+        area: Background::from_repr(deser_u8(bytes)? as usize)?,
+        route_policy: RoutePolicy::from_repr(deser_u8(bytes)? as usize)?,
+        modifiers: EnumSet::try_from_u8(deser_u8(bytes)?)?,
+        stop_rules: deser_stop_rules(bytes)?,
+        last_stop: deser_stop_reason(bytes)?,
+        early_deaths: deser_u16(bytes)?,
+        last_run: None,
+        history: {
+            let len = deser_u8(bytes)?;
+            (0..len).map(|_| deser_run_record(bytes).map(Rc::new)).collect::<Option<_>>()?
+        },
+    })
+}
+fn deser_stop_reason(bytes: &mut &[u8]) -> Option<Option<StopReason>> {
+    match deser_u8(bytes)? {
+        0 => Some(None),
+        reason => Some(Some(StopReason::from_repr(reason as usize - 1)?)),
+    }
+}
+
+fn ser_dungeon(bytes: &mut Vec<u8>, dungeon: &Dungeon) {
+    // TODO ser the auto restart bit outside
+    ser_u8(bytes, dungeon.finished as u8);
+    if dungeon.finished {
+        return;
+    }
+
+    ser_equipment_set(bytes, &dungeon.starting_equip.equipment_set, ser_dungeon_item);
+    ser_u64(bytes, dungeon.tick);
+    ser_u8(bytes, dungeon.area.background as u8);
+    ser_u8(bytes, dungeon.floor.route_policy as u8);
+    ser_u8(bytes, dungeon.floor.modifiers.as_u8());
+    ser_stop_rules(bytes, &dungeon.stop_rules);
+    bytes.extend_from_slice(&dungeon.rng.get_seed());
+    ser_u32(bytes, dungeon_checksum(&dungeon.floor))
+}
+fn deser_dungeon(bytes: &mut &[u8]) -> Option<Dungeon> {
+    let finished = deser_u8(bytes)? != 0;
+    if finished {
+        return Some(Dungeon::dummy());
+    }
+
+    let starting_equip = deser_dungeon_equipment_set(bytes)?;
+    let tick = deser_u64(bytes)?;
+    let background = Background::from_repr(deser_u8(bytes)? as usize)?;
+    let route_policy = RoutePolicy::from_repr(deser_u8(bytes)? as usize)?;
+    let modifiers = EnumSet::try_from_u8(deser_u8(bytes)?)?;
+    let stop_rules = deser_stop_rules(bytes)?;
+    let seed = deser_bytes(bytes)?;
+
+    let mut dungeon = Dungeon::new(&starting_equip.equipment_set, background, route_policy, modifiers, stop_rules, seed);
+
+    for _ in 0..tick {
+        dungeon.tick();
+    }
+
+    // a run that plays out differently than it did is dropped, the rest of the save is still fine
+    let checksum = deser_u32(bytes)?;
+    if checksum != dungeon_checksum(&dungeon.floor) {
+        error!("Deserialized Dungeon has diverging checksum after simulating {} ticks", tick);
+        return Some(Dungeon::dummy());
+    }
+
+    Some(dungeon)
+}
+
+// 0 means the rule is off, none of them make sense at 0
+fn ser_stop_rules(bytes: &mut Vec<u8>, rules: &StopRules) {
+    ser_u16(bytes, rules.depth.unwrap_or(0));
+    ser_u8(bytes, rules.health.unwrap_or(0));
+    ser_u16(bytes, rules.minutes.unwrap_or(0));
+    let early_deaths = rules.early_deaths.map_or((0, 0), |early| (early.runs, early.depth));
+    ser_u16(bytes, early_deaths.0);
+    ser_u16(bytes, early_deaths.1);
+}
+fn deser_stop_rules(bytes: &mut &[u8]) -> Option<StopRules> {
+    let depth = deser_u16(bytes)?;
+    let health = deser_u8(bytes)?;
+    let minutes = deser_u16(bytes)?;
+    let (runs, early_depth) = (deser_u16(bytes)?, deser_u16(bytes)?);
+    Some(StopRules {
+        depth: (depth > 0).then_some(depth),
+        health: (health > 0).then_some(health),
+        minutes: (minutes > 0).then_some(minutes),
+        early_deaths: (runs > 0).then_some(EarlyDeaths { runs, depth: early_depth }),
+    })
+}
+
+// the items of a dungeon are copies that live in it, not in the stash
+fn ser_dungeon_item(bytes: &mut Vec<u8>, item: &Weak<Item>) {
+    if let Some(item) = item.upgrade() {
+        ser_item(bytes, &item, false);
+    } else {
+        ser_u8(bytes, 0);
+    }
+}
+fn deser_dungeon_equipment_set(bytes: &mut &[u8]) -> Option<OwningEquipmentSet> {
+    let mut items: Vec<Rc<Item>> = Vec::new();
+    let set = deser_equipment_set(bytes, |bytes| deser_dungeon_item(bytes, &mut items))?;
+    Some(OwningEquipmentSet::from(&set))
+}
+pub(super) fn deser_dungeon_item(bytes: &mut &[u8], items: &mut Vec<Rc<Item>>) -> Option<ItemRef> {
+    if *bytes.first()? == 0 {
+        deser_u8(bytes)?;
+        return Some(Weak::new());
+    }
+
+    static ID_COUNTER: AtomicUsize = AtomicUsize::new(1);
+
+    let mut item = deser_item(bytes, false)?;
+    item.id = ID_COUNTER.fetch_add(1, Ordering::Relaxed);
+    let item = Rc::new(item);
+    let weak = Rc::downgrade(&item);
+    items.push(item);
+    Some(weak)
+}
+
+fn ser_run_record(bytes: &mut Vec<u8>, record: &RunRecord) {
+    bytes.extend_from_slice(&record.seed);
+    ser_equipment_set(bytes, &record.starting_equip.equipment_set, ser_dungeon_item);
+    ser_u8(bytes, record.background as u8);
+    ser_u8(bytes, record.route_policy as u8);
+    ser_u8(bytes, record.modifiers.as_u8());
+    ser_stop_rules(bytes, &record.stop_rules);
+    ser_u64(bytes, record.ticks);
+    ser_u16(bytes, record.depth);
+    ser_u8(bytes, record.stop_reason.map_or(0, |reason| reason as u8 + 1));
+}
+fn deser_run_record(bytes: &mut &[u8]) -> Option<RunRecord> {
+    Some(RunRecord {
+        seed: deser_bytes(bytes)?,
+        starting_equip: deser_dungeon_equipment_set(bytes)?,
+        background: Background::from_repr(deser_u8(bytes)? as usize)?,
+        route_policy: RoutePolicy::from_repr(deser_u8(bytes)? as usize)?,
+        modifiers: EnumSet::try_from_u8(deser_u8(bytes)?)?,
+        stop_rules: deser_stop_rules(bytes)?,
+        ticks: deser_u64(bytes)?,
+        depth: deser_u16(bytes)?,
+        stop_reason: deser_stop_reason(bytes)?,
+    })
+}
+
+pub(crate) fn dungeon_checksum(level: &Floor) -> u32 {
+    let mut checksum = Vec::new();
+
+    ser_u16(&mut checksum, level.depth);
+    ser_u32(&mut checksum, level.transition.unwrap_or(0));
+    ser_u64(&mut checksum, level.battle.tick);
+
+    for combatant in [
+        &level.battle.fighter,
+        level.battle.ranger.as_ref().unwrap_or(&level.battle.fighter),
+        level.battle.mage.as_ref().unwrap_or(&level.battle.fighter),
+    ].into_iter().chain(level.battle.enemies.iter()) {
+        ser_f32(&mut checksum, combatant.health);
+        ser_f32(&mut checksum, combatant.shield);
+        for skill in &combatant.skills {
+            ser_u16(&mut checksum, skill.cd);
+        }
+    }
+
+    CRC.checksum(&checksum)
+}
+
+// --
+
+fn ser_item(bytes: &mut Vec<u8>, item: &Item, with_tags: bool) {
+    ser_u8(bytes, item.item_type as u8);
+    ser_targeting(bytes, &item.targeting);
+    ser_mods(bytes, &item.mods);
+    ser_u8(bytes, item.rerolled_mod_idx);
+    if with_tags {
+        ser_u8(bytes, item.tags.rating() as u8);
+    }
+}
+pub(super) fn deser_item(bytes: &mut &[u8], with_tags: bool) -> Option<Item> {
+    let mut item = Item {
+        id: 0,
+        item_type: ItemType::from_repr(deser_u8(bytes)?)?,
+        targeting: deser_targeting(bytes)?,
+        mods: deser_mods(bytes)?,
+        rerolled_mod_idx: deser_u8(bytes)?,
+        tags: if with_tags {
+            ItemTags::from_rating(Rating::from_repr(deser_u8(bytes)?)?)
+        } else {
+            Default::default()
+        },
+        attunements: Vec::new(),
+    };
+    item.recompute_attunements();
+    Some(item)
+}
+
+fn ser_targeting(bytes: &mut Vec<u8>, targeting: &Option<Targeting>) {
+    use Targeting::*;
+    match targeting {
+        None                                      => ser_u8(bytes, 0),
+        Some(First)                               => ser_u8(bytes, 1),
+        Some(LowestHealth)                        => ser_u8(bytes, 2),
+        Some(LowestResistance(Element::Bleed))    => ser_u8(bytes, 3),
+        Some(LowestResistance(Element::Fracture)) => ser_u8(bytes, 4),
+        Some(LowestResistance(Element::Madness))  => ser_u8(bytes, 5),
+        Some(LowestResistance(Element::Void))     => ser_u8(bytes, 6),
+        Some(HighestMaxHealth)                    => ser_u8(bytes, 7),
+        Some(HighestDamage)                       => ser_u8(bytes, 8),
+        Some(RoundRobin(_))                       => ser_u8(bytes, 9),
+        Some(MostDebuffs)                         => ser_u8(bytes, 10),
+        Some(MissingDebuff(icon))                 => { ser_u8(bytes, 11); ser_u8(bytes, *icon as u8) },
+        Some(FocusAlly)                           => ser_u8(bytes, 12),
+        Some(LowestShield)                        => ser_u8(bytes, 13),
+
+        Some(Instant)                             => ser_u8(bytes, 100),
+        Some(OnAttack)                            => ser_u8(bytes, 101),
+        Some(BelowHealth(percent))                => { ser_u8(bytes, 102); ser_u8(bytes, *percent) },
+    };
+}
+fn deser_targeting(bytes: &mut &[u8]) -> Option<Option<Targeting>> {
+    use Targeting::*;
+    match deser_u8(bytes)? {
+        0 => Some(None),
+        1 => Some(Some(First)),
+        2 => Some(Some(LowestHealth)),
+        3 => Some(Some(LowestResistance(Element::Bleed))),
+        4 => Some(Some(LowestResistance(Element::Fracture))),
+        5 => Some(Some(LowestResistance(Element::Madness))),
+        6 => Some(Some(LowestResistance(Element::Void))),
+        7 => Some(Some(HighestMaxHealth)),
+        8 => Some(Some(HighestDamage)),
+        9 => Some(Some(RoundRobin(0))),
+        10 => Some(Some(MostDebuffs)),
+        11 => Some(Some(MissingDebuff(BuffIcon::from_repr(deser_u8(bytes)? as usize)?))),
+        12 => Some(Some(FocusAlly)),
+        13 => Some(Some(LowestShield)),
+
+        100   => Some(Some(Instant)),
+        101   => Some(Some(OnAttack)),
+        102   => Some(Some(BelowHealth(deser_u8(bytes)?))),
+        _ => None
+    }
+}
+
+fn ser_mods(bytes: &mut Vec<u8>, mods: &Vec<RolledMod>) {
+    ser_u8(bytes, mods.len() as u8);
+    for item_mod in mods {
+        ser_u16(bytes, item_mod.mod_id);
+        ser_u16(bytes, item_mod.roll);
+    }
+}
+fn deser_mods(bytes: &mut &[u8]) -> Option<Vec<RolledMod>> {
+    let len = deser_u8(bytes)?;
+    (0..len).map(|_| 
+        Some(RolledMod {
+            mod_id: deser_u16(bytes)?,
+            roll: deser_u16(bytes)?,
+        })
+    ).collect()
+}
+
+// numbers
+
+fn ser_u8(bytes: &mut Vec<u8>, u: u8) {
+    bytes.push(u);
+}
+fn ser_u16(bytes: &mut Vec<u8>, u: u16) {
+    bytes.extend_from_slice(&u.to_le_bytes());
+}
+fn ser_u32(bytes: &mut Vec<u8>, u: u32) {
+    bytes.extend_from_slice(&u.to_le_bytes());
+}
+fn ser_u64(bytes: &mut Vec<u8>, u: u64) {
+    bytes.extend_from_slice(&u.to_le_bytes());
+}
+fn ser_f32(bytes: &mut Vec<u8>, f: f32) {
+    bytes.extend_from_slice(&f.to_le_bytes());
+}
+
+pub(super) fn deser_u8(bytes: &mut &[u8]) -> Option<u8> {
+    let (first, rest) = bytes.split_first()?;
+    *bytes = rest;
+    Some(*first)
+}
+pub(super) fn deser_bytes<const N: usize>(bytes: &mut &[u8]) -> Option<[u8; N]> {
+    let (first, rest) = bytes.split_first_chunk::<N>()?;
+    *bytes = rest;
+    Some(*first)
+}
+pub(super) fn deser_u16(bytes: &mut &[u8]) -> Option<u16> {
+    Some(u16::from_le_bytes(deser_bytes(bytes)?))
+}
+pub(super) fn deser_u32(bytes: &mut &[u8]) -> Option<u32> {
+    Some(u32::from_le_bytes(deser_bytes(bytes)?))
+}
+pub(super) fn deser_u64(bytes: &mut &[u8]) -> Option<u64> {
+    Some(u64::from_le_bytes(deser_bytes(bytes)?))
+}
+fn _deser_f32(bytes: &mut &[u8]) -> Option<f32> {
+    Some(f32::from_le_bytes(deser_bytes(bytes)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use rand_chacha::ChaCha12Rng;
+
+    use crate::equipment::{equipment::Equip, wardrobe::ItemSlot};
+
+    use super::*;
+
+    // a party that gets a few floors deep, the items go into the stash so the wardrobe can point at them
+    fn party_set(stash: &mut Stash, seed: u64) -> EquipmentSet {
+        let mut rng = ChaCha12Rng::seed_from_u64(seed);
+        let mut item = |item_type| {
+            stash.add(Item::random(&mut rng, item_type, 5));
+            Rc::downgrade(stash.items().last().unwrap())
+        };
+        let mut set = EquipmentSet::default();
+        set.fighter_equip.set_item(item(ItemType::Axe), ItemSlot::Weapon(0));
+        set.fighter_equip.set_item(item(ItemType::Armor), ItemSlot::Armor);
+        set.ranger_equip.set_item(item(ItemType::Crossbow), ItemSlot::Weapon(0));
+        set.ranger_equip.set_item(item(ItemType::Satchel), ItemSlot::RangerSatchel);
+        set.mage_equip.set_item(item(ItemType::AttackGem), ItemSlot::Weapon(0));
+        set.mage_equip.set_item(item(ItemType::Staff), ItemSlot::MageStaff);
+        set
+    }
+    fn dungeon(set: &EquipmentSet, seed: u8) -> Dungeon {
+        Dungeon::new(set, Background::Forest, RoutePolicy::default(), EnumSet::empty(), StopRules::default(), [seed; 32])
+    }
+
+    #[test]
+    fn same_seed_and_ticks_give_the_same_checksum() {
+        let mut stash = Stash::default();
+        let set = party_set(&mut stash, 1);
+        let (mut a, mut b) = (dungeon(&set, 1), dungeon(&set, 1));
+        for _ in 0..1500 {
+            a.tick();
+            b.tick();
+            assert_eq!(dungeon_checksum(&a.floor), dungeon_checksum(&b.floor));
+        }
+        assert!(a.floor.depth > 1, "the run should get past the first floor");
+    }
+
+    #[test]
+    fn a_run_in_progress_survives_a_round_trip() {
+        let mut stash = Stash::default();
+        let mut wardrobe = Wardrobe::default();
+        wardrobe.sets[0] = party_set(&mut stash, 2);
+        wardrobe.sets[0].iter().filter_map(|i| i.upgrade()).for_each(|i| i.tags.add_wardrobe(0));
+        let mut dungeon = DungeonData::default();
+        dungeon.selected_mut().cur = self::dungeon(&wardrobe.sets[0], 2);
+        for _ in 0..1500 {
+            dungeon.tick(&wardrobe);
+        }
+        let before = &dungeon.selected().cur;
+        assert!(!before.finished);
+
+        let save = deser(&ser(&stash, &wardrobe, &dungeon, 0)).unwrap();
+        // deser drops a run whose checksum does not match, so the tick would be gone
+        let after = &save.dungeon.selected().cur;
+        assert_eq!(after.tick, before.tick);
+        assert_eq!(dungeon_checksum(&after.floor), dungeon_checksum(&before.floor));
+    }
+}
@@ -1,6 +1,6 @@
 use rand::{distr::{uniform::{SampleBorrow, SampleUniform}, weighted::Weight}, seq::{IndexedRandom, IteratorRandom, SliceChooseIter}, Rng};
 
-#[derive(PartialEq, PartialOrd)]
+#[derive(PartialEq)]
 pub struct F32Ord(pub f32);
 impl Eq for F32Ord {}
 impl Ord for F32Ord {
@@ -8,6 +8,31 @@ impl Ord for F32Ord {
         self.0.total_cmp(&other.0)
     }
 }
+impl PartialOrd for F32Ord {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+pub trait NumExt {
+    fn at_least(self, lower_limit: Self) -> Self;
+    fn at_most(self, upper_limit: Self) -> Self;
+}
+macro_rules! impl_num_ext {
+    ($($t:ty),*) => {$(
+        impl NumExt for $t {
+            #[inline(always)]
+            fn at_least(self, lower_limit: Self) -> Self {
+                self.max(lower_limit)
+            }
+            #[inline(always)]
+            fn at_most(self, upper_limit: Self) -> Self {
+                self.min(upper_limit)
+            }
+        }
+    )*};
+}
+impl_num_ext!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64);
 
 pub trait SplitOption<A, B> {
     fn split(self) -> (Option<A>, Option<B>);
@@ -51,4 +76,4 @@ pub trait IteratorRandomNonEmpty: IteratorRandom {
         self.choose_stable(rng).unwrap()
     }
 }
-impl <IR: IteratorRandom + ?Sized> IteratorRandomNonEmpty for IR {}
+impl <IR: IteratorRandom> IteratorRandomNonEmpty for IR {}
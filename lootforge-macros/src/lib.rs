@@ -58,7 +58,7 @@ fn tokenize_string(input: String) -> Vec<Token> {
         tokens.push(Token::from(token));
         s = rest;
     }
-    if s.len() > 0 {
+    if !s.is_empty() {
         tokens.push(Token::Text(s.to_owned()));
     }
     tokens
@@ -66,91 +66,48 @@ fn tokenize_string(input: String) -> Vec<Token> {
 
 fn wrap_tokens(tokens: Vec<Token>) -> TokenStream {
     [
-        Ident(Ident::new("ui", Span::call_site())),
-        Punct(Punct::new('.', Spacing::Alone)),
-        Ident(Ident::new("horizontal", Span::call_site())),
-        Group(Group::new(Delimiter::Parenthesis, [
-            Punct(Punct::new('|', Spacing::Alone)),
-            Ident(Ident::new("ui", Span::call_site())),
-            Punct(Punct::new('|', Spacing::Alone)),
-            Group(Group::new(Delimiter::Brace, 
-                call("ui", "spacing_mut", TokenStream::new())
-                .chain([                    
-                    Punct(Punct::new('.', Spacing::Alone)),
-                    Ident(Ident::new("item_spacing", Span::call_site())),
-                    Punct(Punct::new('=', Spacing::Alone)),
-                    Ident(Ident::new("vec2", Span::call_site())),
-                    Group(Group::new(Delimiter::Parenthesis, [
-                        Literal(Literal::f32_unsuffixed(0.)),
-                        Punct(Punct::new(',', Spacing::Alone)),
-                        Literal(Literal::f32_unsuffixed(0.)),
-                    ].into_iter().collect())),
-                    Punct(Punct::new(';', Spacing::Alone)),
-                ].into_iter())
-                .chain(tokens.into_iter().flat_map(|t| translate_token(t)))
+        Punct(Punct::new('&', Spacing::Alone)),
+        Group(Group::new(Delimiter::Bracket,
+            tokens.into_iter()
+                .filter(|t| !matches!(t, Token::Text(text) if text.is_empty()))
+                .flat_map(|t| translate_token(t).chain(iter::once(Punct(Punct::new(',', Spacing::Alone)))))
                 .collect()
-            ))
-        ].into_iter().collect()))
+        )),
     ].into_iter().collect()
 }
 
-fn translate_token(token: Token) -> impl Iterator<Item=TokenTree> {
+fn translate_token(token: Token) -> std::vec::IntoIter<TokenTree> {
     match token {
-        Token::Text(text) =>
-            if text.len() > 0 {call("ui", "label", Literal(Literal::string(&text)).into())} else { vec![].into_iter() },
-        Token::Roll => 
-            call("ui", "label", call("roll", "to_string", TokenStream::new()).collect()),
-        Token::Range => 
-            call("ui", "colored_label", [
-                Ident(Ident::new("Color32", Span::call_site())),
-                Punct(Punct::new(':', Spacing::Joint)),
-                Punct(Punct::new(':', Spacing::Alone)),
-                Ident(Ident::new("GRAY", Span::call_site())),
-                Punct(Punct::new(',', Spacing::Alone)),
-                Ident(Ident::new("format", Span::call_site())),
-                Punct(Punct::new('!', Spacing::Alone)),
-                Group(Group::new(Delimiter::Parenthesis,
-                    // TODO clean this mess a bit
-                [
-                    Literal(Literal::string("({}-{})")),
-                    Punct(Punct::new(',', Spacing::Alone)),
-                ].into_iter()
-                    .chain(iter::once(Ident(Ident::new("this", Span::call_site()))))
-                    .chain(iter::once(Punct(Punct::new('.', Spacing::Alone))))
-                    .chain(call("roll_range", "start", TokenStream::new()))
-                    .chain(iter::once(Punct(Punct::new(',', Spacing::Alone))))
-                    .chain(iter::once(Ident(Ident::new("this", Span::call_site()))))
-                    .chain(iter::once(Punct(Punct::new('.', Spacing::Alone))))
-                    .chain(call("roll_range", "end", TokenStream::new()))
-                    .collect())),
-            ].into_iter().collect()),
-        Token::Bleed    => element("Bleed"),
-        Token::Fracture => element("Fracture"),
-        Token::Madness  => element("Madness"),
-        Token::Void     => element("Void"),
+        Token::Text(text) => part("Text", Some(Literal(Literal::string(&text)).into())),
+        Token::Roll       => part("Roll", None),
+        Token::Range      => part("Range", None),
+        Token::Bleed      => element("Bleed"),
+        Token::Fracture   => element("Fracture"),
+        Token::Madness    => element("Madness"),
+        Token::Void       => element("Void"),
     }
-    .chain(iter::once(Punct(Punct::new(';', Spacing::Alone))))
 }
 
-fn call(obj: &str, fun: &str, args: TokenStream) -> std::vec::IntoIter<TokenTree> {
-    vec![
-        Ident(Ident::new(obj, Span::call_site())),
-        Punct(Punct::new('.', Spacing::Alone)),
-        Ident(Ident::new(fun, Span::call_site())),
-        Group(Group::new(Delimiter::Parenthesis, args)),
-    ].into_iter()
+fn path(segments: &[&str]) -> Vec<TokenTree> {
+    let mut tokens = Vec::new();
+    for (i, segment) in segments.iter().enumerate() {
+        if i > 0 {
+            tokens.push(Punct(Punct::new(':', Spacing::Joint)));
+            tokens.push(Punct(Punct::new(':', Spacing::Alone)));
+        }
+        tokens.push(Ident(Ident::new(segment, Span::call_site())));
+    }
+    tokens
+}
+
+fn part(variant: &str, arg: Option<TokenStream>) -> std::vec::IntoIter<TokenTree> {
+    let mut tokens = path(&["crate", "mods", "TooltipPart", variant]);
+    if let Some(arg) = arg {
+        tokens.push(Group(Group::new(Delimiter::Parenthesis, arg)));
+    }
+    tokens.into_iter()
 }
 
 fn element(name: &str) -> std::vec::IntoIter<TokenTree> {
-    call("ui", "colored_label", [
-        Ident(Ident::new("Element", Span::call_site())),
-        Punct(Punct::new(':', Spacing::Joint)),
-        Punct(Punct::new(':', Spacing::Alone)),
-        Ident(Ident::new(name, Span::call_site())),
-        Punct(Punct::new('.', Spacing::Alone)),
-        Ident(Ident::new("color", Span::call_site())),
-        Group(Group::new(Delimiter::Parenthesis, TokenStream::new())),
-        Punct(Punct::new(',', Spacing::Alone)),
-        Literal(Literal::string(&name.to_lowercase())),
-    ].into_iter().collect())
-}
\ No newline at end of file
+    part("Element", Some(path(&["crate", "elemental", "Element", name]).into_iter().collect()))
+}
@@ -0,0 +1,170 @@
+use std::rc::Rc;
+
+use lootforge_core::{
+    combat::{buff::BuffIcon, skill::targeting::Targeting},
+    elemental::Element,
+    equipment::{equipment::Equip, wardrobe::{EquipmentSet, ItemSlot}},
+    explorer::Explorer,
+    item::{item::Item, item_type::ItemType},
+    mods::{roll_tables::ALL_MODS, RolledMod},
+    prelude::*,
+    stash::stash::Stash,
+};
+
+// A loadout file has one item per line, empty lines and everything after a # are ignored:
+//   <slot> <item type> [targeting] <mod id>[:<roll>] ...
+// e.g.
+//   weapon1 axe 1:40 5:15
+//   ring1 ring lowest_health 1:30
+//   ranger_weapon1 bow 2:25
+// the roll can be left out for mods without a roll range, ranger and mage slots are prefixed with ranger_ and mage_
+pub struct Loadout {
+    // owns the items, the equipment set only holds weak refs
+    _stash: Stash,
+    pub equipment: EquipmentSet,
+}
+
+impl Loadout {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut stash = Stash::default();
+        let mut equipment = EquipmentSet::default();
+
+        for (line_nr, line) in text.lines().enumerate() {
+            let line = line.split('#').next().unwrap().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let (explorer, slot, item) = parse_line(line).map_err(|e| format!("line {}: {}", line_nr + 1, e))?;
+
+            stash.add(item);
+            let item = stash.items().last().unwrap();
+            if !slot.accepts(explorer, item) {
+                return Err(format!("line {}: {:?} can not be equipped in {:?} {:?}", line_nr + 1, item.item_type, explorer, slot));
+            }
+            match explorer {
+                Explorer::Fighter => equipment.fighter_equip.set_item(Rc::downgrade(item), slot),
+                Explorer::Ranger => equipment.ranger_equip.set_item(Rc::downgrade(item), slot),
+                Explorer::Mage => equipment.mage_equip.set_item(Rc::downgrade(item), slot),
+            };
+        }
+
+        Ok(Self { _stash: stash, equipment })
+    }
+}
+
+fn parse_line(line: &str) -> Result<(Explorer, ItemSlot, Item), String> {
+    let mut words = line.split_whitespace().peekable();
+
+    let (explorer, slot) = parse_slot(words.next().unwrap())?;
+    let item_type = parse_item_type(words.next().ok_or("missing item type")?)?;
+
+    let targeting = match words.peek() {
+        Some(word) if !word.starts_with(|c: char| c.is_ascii_digit()) => Some(parse_targeting(words.next().unwrap())?),
+        _ => None,
+    };
+    if targeting.is_some() && !item_type.has_targeting() {
+        return Err(format!("{:?} has no targeting", item_type));
+    }
+
+    let mods = words.map(parse_mod).collect::<Result<Vec<_>, _>>()?;
+
+    Ok((explorer, slot, Item::new(item_type, mods, targeting)))
+}
+
+fn parse_slot(word: &str) -> Result<(Explorer, ItemSlot), String> {
+    if let Some(ranger_slot) = word.strip_prefix("ranger_") {
+        return Ok((Explorer::Ranger, match ranger_slot {
+            "weapon1" => ItemSlot::Weapon(0),
+            "weapon2" => ItemSlot::Weapon(1),
+            "quiver"  => ItemSlot::RangerQuiver,
+            "satchel" => ItemSlot::RangerSatchel,
+            "helmet"  => ItemSlot::Helmet,
+            "armor"   => ItemSlot::Armor,
+            "gloves"  => ItemSlot::Gloves,
+            "ring1"   => ItemSlot::Ring(0),
+            "ring2"   => ItemSlot::Ring(1),
+            _ => return Err(format!("unknown slot: {}", word)),
+        }));
+    }
+    if let Some(mage_slot) = word.strip_prefix("mage_") {
+        return Ok((Explorer::Mage, match mage_slot {
+            "weapon1" => ItemSlot::Weapon(0),
+            "weapon2" => ItemSlot::Weapon(1),
+            "support" => ItemSlot::MageSupportGem,
+            "staff"   => ItemSlot::MageStaff,
+            "helmet"  => ItemSlot::Helmet,
+            "armor"   => ItemSlot::Armor,
+            "gloves"  => ItemSlot::Gloves,
+            "ring1"   => ItemSlot::Ring(0),
+            "ring2"   => ItemSlot::Ring(1),
+            _ => return Err(format!("unknown slot: {}", word)),
+        }));
+    }
+    Ok((Explorer::Fighter, match word {
+        "weapon1" => ItemSlot::Weapon(0),
+        "weapon2" => ItemSlot::Weapon(1),
+        "shield"  => ItemSlot::FighterShield,
+        "helmet"  => ItemSlot::Helmet,
+        "armor"   => ItemSlot::Armor,
+        "gloves"  => ItemSlot::Gloves,
+        "ring1"   => ItemSlot::Ring(0),
+        "ring2"   => ItemSlot::Ring(1),
+        "ring3"   => ItemSlot::Ring(2),
+        _ => return Err(format!("unknown slot: {}", word)),
+    }))
+}
+
+fn parse_item_type(word: &str) -> Result<ItemType, String> {
+    ItemType::iter()
+        .find(|t| <&'static str>::from(*t).eq_ignore_ascii_case(word))
+        .ok_or_else(|| format!("unknown item type: {}", word))
+}
+
+fn parse_targeting(word: &str) -> Result<Targeting, String> {
+    use Targeting::*;
+    if let Some(element) = word.strip_prefix("lowest_resistance_") {
+        let element: Element = element.parse().map_err(|_| format!("unknown element: {}", element))?;
+        return Ok(LowestResistance(element));
+    }
+    if let Some(percent) = word.strip_prefix("below_health_") {
+        let percent: u8 = percent.parse().map_err(|_| format!("invalid health percent: {}", percent))?;
+        return Ok(BelowHealth(percent));
+    }
+    if let Some(debuff) = word.strip_prefix("missing_debuff_") {
+        let debuff = BuffIcon::DEBUFFS.into_iter()
+            .find(|d| <&'static str>::from(*d).eq_ignore_ascii_case(debuff))
+            .ok_or_else(|| format!("unknown debuff: {}", debuff))?;
+        return Ok(MissingDebuff(debuff));
+    }
+    Ok(match word {
+        "lowest_health"      => LowestHealth,
+        "highest_max_health" => HighestMaxHealth,
+        "highest_damage"     => HighestDamage,
+        "round_robin"        => RoundRobin(0),
+        "most_debuffs"       => MostDebuffs,
+        "focus_ally"         => FocusAlly,
+        "lowest_shield"      => LowestShield,
+        "instant"            => Instant,
+        "on_attack"          => OnAttack,
+        _ => return Err(format!("unknown targeting: {}", word)),
+    })
+}
+
+fn parse_mod(word: &str) -> Result<RolledMod, String> {
+    let (id, roll) = match word.split_once(':') {
+        Some((id, roll)) => (id, Some(roll)),
+        None => (word, None),
+    };
+    let id: u16 = id.parse().map_err(|_| format!("invalid mod id: {}", id))?;
+    let mod_type = ALL_MODS.get(&id).ok_or_else(|| format!("unknown mod id: {}", id))?;
+
+    let roll = match roll {
+        Some(roll) => roll.parse().map_err(|_| format!("invalid roll: {}", roll))?,
+        None => *mod_type.roll_range.start(),
+    };
+    if !mod_type.roll_range.contains(&roll) {
+        return Err(format!("roll {} of {} is outside of {:?}", roll, mod_type.prefix_name, mod_type.roll_range));
+    }
+
+    Ok(RolledMod { mod_id: id, roll })
+}
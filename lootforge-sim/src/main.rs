@@ -0,0 +1,158 @@
+#![warn(clippy::all, rust_2018_idioms)]
+
+use std::{fs, process::ExitCode};
+
+use enumset::EnumSet;
+use lootforge_core::{dungeon::{dungeon::Background, floor::RoutePolicy, modifier::DungeonModifier, stop::StopRules}, prelude::*};
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaCha12Rng;
+
+use loadout::Loadout;
+use report::Report;
+
+mod loadout;
+mod report;
+mod sim;
+
+const USAGE: &str = "\
+usage: lootforge-sim <loadout file> [options]
+
+options:
+  --runs <n>            number of runs to simulate (default 1000)
+  --seed <n>            base seed, every run gets its own seed derived from it (default 0)
+  --max-ticks <n>       cancel runs that are still alive after this many ticks (default 1000000)
+  --area <a>            forest, redforest, snow, desert, cave or dungeon (default dungeon)
+  --route <r>           greedy, safest or fixed, how to pick between forks on a floor (default fixed)
+  --modifiers <m>       comma separated dungeon modifiers: brutal, warded, shielded, hasty, crowded (default none)
+  --retreat-depth <n>   retreat once this many floors are cleared
+  --retreat-health <n>  retreat when the party goes into a battle below this percent of its health
+  --format <f>          csv (one row per run) or json (summary and runs) (default csv)
+  --out <file>          write the report to a file instead of stdout
+
+a short summary is always printed to stderr";
+
+enum Format {
+    Csv,
+    Json,
+}
+
+struct Args {
+    loadout: String,
+    runs: usize,
+    seed: u64,
+    max_ticks: u64,
+    area: Background,
+    route: RoutePolicy,
+    modifiers: EnumSet<DungeonModifier>,
+    stop_rules: StopRules,
+    format: Format,
+    out: Option<String>,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = std::env::args().skip(1);
+    let mut parsed = Args {
+        loadout: String::new(),
+        runs: 1000,
+        seed: 0,
+        max_ticks: 1_000_000,
+        area: Background::default(),
+        route: RoutePolicy::default(),
+        modifiers: EnumSet::empty(),
+        stop_rules: StopRules::default(),
+        format: Format::Csv,
+        out: None,
+    };
+
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("missing value for {}", arg));
+        match arg.as_str() {
+            "--runs"           => parsed.runs = value()?.parse().map_err(|e| format!("--runs: {}", e))?,
+            "--seed"           => parsed.seed = value()?.parse().map_err(|e| format!("--seed: {}", e))?,
+            "--max-ticks"      => parsed.max_ticks = value()?.parse().map_err(|e| format!("--max-ticks: {}", e))?,
+            "--area"           => parsed.area = {
+                let area = value()?;
+                Background::iter()
+                    .find(|b| <&'static str>::from(*b).eq_ignore_ascii_case(&area))
+                    .ok_or_else(|| format!("unknown area: {}", area))?
+            },
+            "--route"          => parsed.route = {
+                let route = value()?;
+                RoutePolicy::iter()
+                    .find(|r| <&'static str>::from(*r).eq_ignore_ascii_case(&route))
+                    .ok_or_else(|| format!("unknown route: {}", route))?
+            },
+            "--modifiers"      => parsed.modifiers = value()?.split(',')
+                .map(|word| DungeonModifier::iter()
+                    .find(|m| <&'static str>::from(*m).eq_ignore_ascii_case(word))
+                    .ok_or_else(|| format!("unknown modifier: {}", word)))
+                .collect::<Result<_, _>>()?,
+            "--retreat-depth"  => parsed.stop_rules.depth = Some(value()?.parse().map_err(|e| format!("--retreat-depth: {}", e))?),
+            "--retreat-health" => parsed.stop_rules.health = Some(value()?.parse().map_err(|e| format!("--retreat-health: {}", e))?),
+            "--format"         => parsed.format = match value()?.as_str() {
+                "csv" => Format::Csv,
+                "json" => Format::Json,
+                f => return Err(format!("unknown format: {}", f)),
+            },
+            "--out"            => parsed.out = Some(value()?),
+            "-h" | "--help" => return Err(String::new()),
+            _ if arg.starts_with("--") => return Err(format!("unknown option: {}", arg)),
+            _ if parsed.loadout.is_empty() => parsed.loadout = arg,
+            _ => return Err(format!("unexpected argument: {}", arg)),
+        }
+    }
+
+    if parsed.loadout.is_empty() {
+        return Err("missing loadout file".to_owned());
+    }
+    if parsed.runs == 0 {
+        return Err("--runs must be at least 1".to_owned());
+    }
+    Ok(parsed)
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            if !e.is_empty() {
+                eprintln!("{}\n", e);
+            }
+            eprintln!("{}", USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let loadout = match fs::read_to_string(&args.loadout).map_err(|e| e.to_string()).and_then(|text| Loadout::parse(&text)) {
+        Ok(loadout) => loadout,
+        Err(e) => {
+            eprintln!("failed to load {}: {}", args.loadout, e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut seeds = ChaCha12Rng::seed_from_u64(args.seed);
+    let runs = (0..args.runs)
+        .map(|_| {
+            let mut seed = [0; 32];
+            seeds.fill_bytes(&mut seed);
+            sim::run(&loadout.equipment, args.area, args.route, args.modifiers, args.stop_rules, seed, args.max_ticks)
+        })
+        .collect();
+    let report = Report { runs };
+
+    eprint!("{}", report.summary());
+
+    let output = match args.format {
+        Format::Csv => report.csv(),
+        Format::Json => report.json(),
+    };
+    match args.out {
+        Some(file) => if let Err(e) = fs::write(&file, output) {
+            eprintln!("failed to write {}: {}", file, e);
+            return ExitCode::FAILURE;
+        },
+        None => print!("{}", output),
+    }
+    ExitCode::SUCCESS
+}
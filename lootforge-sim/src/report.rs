@@ -0,0 +1,167 @@
+use std::{collections::BTreeMap, fmt::Write, iter::once};
+
+use lootforge_core::{dungeon::reward::RewardChest, item::item::Item};
+
+use crate::sim::RunResult;
+
+pub struct Report {
+    pub runs: Vec<RunResult>,
+}
+
+pub struct Distribution {
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    pub median: f64,
+    pub p10: f64,
+    pub p90: f64,
+}
+
+impl Distribution {
+    fn from(mut values: Vec<f64>) -> Self {
+        values.sort_by(f64::total_cmp);
+        let percentile = |p: f64| values[((values.len() - 1) as f64 * p).round() as usize];
+        Self {
+            min: values[0],
+            max: values[values.len() - 1],
+            mean: values.iter().sum::<f64>() / values.len() as f64,
+            median: percentile(0.5),
+            p10: percentile(0.1),
+            p90: percentile(0.9),
+        }
+    }
+
+    fn json(&self) -> String {
+        format!(
+            r#"{{"min": {}, "max": {}, "mean": {:.2}, "median": {}, "p10": {}, "p90": {}}}"#,
+            self.min, self.max, self.mean, self.median, self.p10, self.p90
+        )
+    }
+}
+
+impl Report {
+    pub fn depth(&self) -> Distribution {
+        Distribution::from(self.runs.iter().map(|r| r.depth as f64).collect())
+    }
+    pub fn ticks(&self) -> Distribution {
+        Distribution::from(self.runs.iter().map(|r| r.ticks as f64).collect())
+    }
+    pub fn depth_histogram(&self) -> BTreeMap<u16, usize> {
+        let mut histogram = BTreeMap::new();
+        self.runs.iter().for_each(|r| *histogram.entry(r.depth).or_default() += 1);
+        histogram
+    }
+    pub fn death_causes(&self) -> BTreeMap<&'static str, usize> {
+        let mut causes = BTreeMap::new();
+        self.runs.iter().for_each(|r| *causes.entry(r.death_cause.name()).or_default() += 1);
+        causes
+    }
+    // item type -> rank -> count
+    pub fn rewards(&self) -> BTreeMap<&'static str, BTreeMap<u8, usize>> {
+        let mut rewards: BTreeMap<_, BTreeMap<_, _>> = BTreeMap::new();
+        self.runs.iter()
+            .flat_map(|r| once(&r.chest).chain(&r.boss_chests).chain(&r.fork_chests))
+            .flat_map(|c| &c.items)
+            .for_each(|i| *rewards.entry(i.item_type.into()).or_default().entry(i.rank()).or_default() += 1);
+        rewards
+    }
+
+    pub fn summary(&self) -> String {
+        let mut out = String::new();
+        let depth = self.depth();
+        let ticks = self.ticks();
+        writeln!(out, "runs:  {}", self.runs.len()).unwrap();
+        writeln!(out, "depth: mean {:.2}, median {}, p10 {}, p90 {}, min {}, max {}", depth.mean, depth.median, depth.p10, depth.p90, depth.min, depth.max).unwrap();
+        writeln!(out, "ticks: mean {:.0}, median {}, p10 {}, p90 {}, min {}, max {}", ticks.mean, ticks.median, ticks.p10, ticks.p90, ticks.min, ticks.max).unwrap();
+        writeln!(out, "depth reached:").unwrap();
+        for (depth, count) in self.depth_histogram() {
+            writeln!(out, "  {:>4}: {}", depth, count).unwrap();
+        }
+        writeln!(out, "killed by:").unwrap();
+        for (cause, count) in self.death_causes() {
+            writeln!(out, "  {:>9}: {}", cause, count).unwrap();
+        }
+        writeln!(out, "rewards (rank: count):").unwrap();
+        for (item_type, ranks) in self.rewards() {
+            let ranks = ranks.iter().map(|(rank, count)| format!("{}: {}", rank, count)).collect::<Vec<_>>().join(", ");
+            writeln!(out, "  {:>9}: {}", item_type, ranks).unwrap();
+        }
+        out
+    }
+
+    // one row per run
+    pub fn csv(&self) -> String {
+        let mut out = String::from("run,seed,depth,ticks,death_cause,reward_depth,rewards,boss_rewards,fork_rewards\n");
+        for (i, run) in self.runs.iter().enumerate() {
+            writeln!(
+                out,
+                "{},{},{},{},{},{},{},{},{}",
+                i,
+                hex(&run.seed),
+                run.depth,
+                run.ticks,
+                run.death_cause.name(),
+                run.chest.depth,
+                run.chest.items.iter().map(item_csv).collect::<Vec<_>>().join(" "),
+                run.boss_chests.iter().flat_map(|c| &c.items).map(item_csv).collect::<Vec<_>>().join(" "),
+                run.fork_chests.iter().flat_map(|c| &c.items).map(item_csv).collect::<Vec<_>>().join(" "),
+            ).unwrap();
+        }
+        out
+    }
+
+    pub fn json(&self) -> String {
+        let mut out = String::from("{\n");
+        writeln!(out, r#"  "runs": {},"#, self.runs.len()).unwrap();
+        writeln!(out, r#"  "depth": {},"#, self.depth().json()).unwrap();
+        writeln!(out, r#"  "ticks": {},"#, self.ticks().json()).unwrap();
+        writeln!(out, r#"  "depth_histogram": {{{}}},"#, self.depth_histogram().iter().map(|(d, c)| format!(r#""{}": {}"#, d, c)).collect::<Vec<_>>().join(", ")).unwrap();
+        writeln!(out, r#"  "death_causes": {{{}}},"#, self.death_causes().iter().map(|(d, c)| format!(r#""{}": {}"#, d, c)).collect::<Vec<_>>().join(", ")).unwrap();
+        let rewards = self.rewards().iter()
+            .map(|(t, ranks)| format!(r#""{}": {{{}}}"#, t, ranks.iter().map(|(r, c)| format!(r#""{}": {}"#, r, c)).collect::<Vec<_>>().join(", ")))
+            .collect::<Vec<_>>()
+            .join(", ");
+        writeln!(out, r#"  "rewards": {{{}}},"#, rewards).unwrap();
+        writeln!(out, r#"  "run_details": ["#).unwrap();
+        for (i, run) in self.runs.iter().enumerate() {
+            write!(
+                out,
+                r#"    {{"seed": "{}", "depth": {}, "ticks": {}, "death_cause": "{}", "chest": {}, "boss_chests": [{}], "fork_chests": [{}]}}"#,
+                hex(&run.seed),
+                run.depth,
+                run.ticks,
+                run.death_cause.name(),
+                chest_json(&run.chest),
+                run.boss_chests.iter().map(chest_json).collect::<Vec<_>>().join(", "),
+                run.fork_chests.iter().map(chest_json).collect::<Vec<_>>().join(", "),
+            ).unwrap();
+            out.push_str(if i + 1 < self.runs.len() { ",\n" } else { "\n" });
+        }
+        out.push_str("  ]\n}\n");
+        out
+    }
+}
+
+pub fn hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut s, b| {
+        write!(s, "{:02x}", b).unwrap();
+        s
+    })
+}
+
+// Axe/2[1:30;5:12]
+fn item_csv(item: &Item) -> String {
+    let item_type: &'static str = item.item_type.into();
+    let mods = item.mods.iter().map(|m| format!("{}:{}", m.mod_id, m.roll)).collect::<Vec<_>>().join(";");
+    format!("{}/{}[{}]", item_type, item.rank(), mods)
+}
+
+fn chest_json(chest: &RewardChest) -> String {
+    format!(r#"{{"depth": {}, "items": [{}]}}"#, chest.depth, chest.items.iter().map(item_json).collect::<Vec<_>>().join(", "))
+}
+
+fn item_json(item: &Item) -> String {
+    let item_type: &'static str = item.item_type.into();
+    let mods = item.mods.iter().map(|m| format!("[{}, {}]", m.mod_id, m.roll)).collect::<Vec<_>>().join(", ");
+    format!(r#"{{"type": "{}", "rank": {}, "mods": [{}]}}"#, item_type, item.rank(), mods)
+}
@@ -0,0 +1,101 @@
+use enumset::EnumSet;
+use lootforge_core::{
+    combat::{combatant::CombatantKind, enemy::EnemyKind, skill::skill::SkillStats},
+    dungeon::{dungeon::{Background, Dungeon}, floor::RoutePolicy, modifier::DungeonModifier, stop::{StopReason, StopRules}, dungeon_data::DungeonTick, reward::{ChestSource, RewardChest}},
+    equipment::wardrobe::EquipmentSet,
+};
+
+pub struct RunResult {
+    pub seed: [u8; 32],
+    pub depth: u16,
+    pub ticks: u64,
+    pub death_cause: DeathCause,
+    pub chest: RewardChest,
+    // dropped by bosses along the way
+    pub boss_chests: Vec<RewardChest>,
+    // found at the end of forks
+    pub fork_chests: Vec<RewardChest>,
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum DeathCause {
+    // the last enemy that hit the party, so DoT deaths are blamed on whoever hit last
+    Enemy(EnemyKind),
+    Unknown,
+    // did not die within max ticks, the run got cancelled
+    Timeout,
+    // a stop rule made the party leave
+    Retreated(StopReason),
+}
+impl DeathCause {
+    pub fn name(&self) -> &'static str {
+        match self {
+            DeathCause::Enemy(kind) => (*kind).into(),
+            DeathCause::Unknown => "Unknown",
+            DeathCause::Timeout => "Timeout",
+            DeathCause::Retreated(StopReason::Depth) => "Retreat (depth)",
+            DeathCause::Retreated(StopReason::Health) => "Retreat (health)",
+            DeathCause::Retreated(StopReason::Time) => "Retreat (time)",
+            DeathCause::Retreated(StopReason::EarlyDeaths) => "Retreat (early deaths)",
+            // dying and cancelling are reported as Enemy and Timeout and simulated runs never come from a save, they never end up here
+            DeathCause::Retreated(reason @ (StopReason::Died | StopReason::Cancelled | StopReason::Outdated)) => reason.describe(),
+        }
+    }
+}
+
+pub fn run(equip: &EquipmentSet, area: Background, route: RoutePolicy, modifiers: EnumSet<DungeonModifier>, stop_rules: StopRules, seed: [u8; 32], max_ticks: u64) -> RunResult {
+    let mut dungeon = Dungeon::new(equip, area, route, modifiers, stop_rules, seed);
+    let mut last_hit_by = None;
+    let mut boss_chests = Vec::new();
+    let mut fork_chests = Vec::new();
+
+    loop {
+        if dungeon.tick >= max_ticks && !dungeon.cancelled {
+            dungeon.cancel();
+        }
+
+        let (tick, chest) = dungeon.tick();
+        if let Some(tick) = tick {
+            if tick.new_battle {
+                last_hit_by = None;
+            }
+            last_hit_by = last_enemy_hit(&tick).or(last_hit_by);
+        }
+
+        if let Some(chest) = chest {
+            match chest.source {
+                ChestSource::Boss => { boss_chests.push(chest); continue; },
+                ChestSource::Fork => { fork_chests.push(chest); continue; },
+                ChestSource::End => {},
+            }
+            let death_cause = match dungeon.stop_reason {
+                Some(StopReason::Died) => last_hit_by.map_or(DeathCause::Unknown, DeathCause::Enemy),
+                Some(StopReason::Cancelled) | None => DeathCause::Timeout,
+                Some(reason) => DeathCause::Retreated(reason),
+            };
+            return RunResult {
+                seed,
+                depth: dungeon.floor.depth,
+                ticks: dungeon.tick,
+                death_cause,
+                chest,
+                boss_chests,
+                fork_chests,
+            };
+        }
+    }
+}
+
+fn last_enemy_hit(tick: &DungeonTick) -> Option<EnemyKind> {
+    tick.skills.iter().rev().find_map(|skill| match skill {
+        SkillStats::Attack(_, attack) => match attack.attacker {
+            CombatantKind::Enemy(_, kind) if attack.hits.iter().any(|h| !h.target.is_enemy()) => Some(kind),
+            _ => None,
+        },
+        SkillStats::Dot(_, dot) => match dot.applier {
+            CombatantKind::Enemy(_, kind) if !dot.target.is_enemy() => Some(kind),
+            _ => None,
+        },
+        SkillStats::Defend(..) | SkillStats::Support(..) => None,
+    })
+}
@@ -1,4 +1,4 @@
-use crate::equipment::wardrobe::Wardrobe;
+use lootforge_core::equipment::wardrobe::Wardrobe;
 #[cfg(debug_assertions)]
 use crate::panels::cheats::CheatsWindow;
 use crate::panels::forge::forge::ForgePanel;
@@ -6,13 +6,12 @@ use crate::panels::header;
 use crate::panels::settings::SettingsWindow;
 use crate::storage::storage_manager::{LoadingState, StorageManager};
 use crate::prelude::*;
+use lootforge_core::{dungeon::dungeon_data::DungeonData, stash::stash::Stash};
 use crate::{
-    dungeon::dungeon_data::DungeonData,
     panels::{
         dungeon::dungeon::DungeonPanel, gear::GearPanel, loot::LootPanel,
         rewards::RewardsWindow,
     },
-    stash::stash::Stash,
     timekeeper::Timekeeper,
 };
 
@@ -87,20 +86,20 @@ impl eframe::App for LootforgeApp {
             let mut dungeon_tick = None;
             while start.elapsed().as_millis() < 100 && target_frames - frames > 100 {
                 for _ in 0..100 {
-                    dungeon_tick = self.dungeon.tick(self.wardrobe.equipped())
+                    dungeon_tick = self.dungeon.tick(&self.wardrobe)
                 }
                 frames += 100;
             }
             if target_frames - frames < 100 {
                 for _ in 0..target_frames - frames {
-                    dungeon_tick = self.dungeon.tick(self.wardrobe.equipped())
+                    dungeon_tick = self.dungeon.tick(&self.wardrobe)
                 }
                 frames = target_frames;
             }
             frame_info.dungeon_tick = dungeon_tick;
             self.timekeeper.report_frames(frames);
         } else if frame_info.tick {
-            let dungeon_tick = self.dungeon.tick(self.wardrobe.equipped());
+            let dungeon_tick = self.dungeon.tick(&self.wardrobe);
             frame_info.dungeon_tick = dungeon_tick;
             self.timekeeper.report_frames(1);
         }
@@ -146,6 +145,13 @@ impl eframe::App for LootforgeApp {
             });
         }
 
+        let battle = match &self.dungeon_panel.replay {
+            Some(replay) => &replay.replay.dungeon.floor.battle,
+            None => &self.dungeon.selected().cur.floor.battle,
+        };
+        self.dungeon_panel.log.show(ctx, battle);
+        self.dungeon_panel.stats.show(ctx, self.dungeon.selected());
+
         CentralPanel::default().show(ctx, |ui| {
             let filter_override = if self.left_panel == LeftPanel::Forge { self.forge.filter() } else { None };
             self.loot_panel.show(ui, &mut self.stash, filter_override);
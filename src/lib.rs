@@ -1,18 +1,10 @@
 #![warn(clippy::all, rust_2018_idioms)]
 
 mod app;
-mod combat;
-mod dungeon;
-mod elemental;
-mod equipment;
-mod explorer;
-mod item;
-mod mods;
 mod panels;
-mod stash;
 mod storage;
 mod timekeeper;
-mod util;
+mod visuals;
 mod widgets;
 
 pub use app::LootforgeApp;
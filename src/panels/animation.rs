@@ -86,6 +86,12 @@ pub enum Animation {
     FighterAttack,
     FighterIdle,
     FighterWalk,
+    RangerAttack,
+    RangerIdle,
+    RangerWalk,
+    MageAttack,
+    MageIdle,
+    MageWalk,
 
     BigWormIdle,
     BigWornAttack,
@@ -117,6 +123,13 @@ impl Animation {
             FighterAttack => &AnimationData { sheet: FighterAttackSheet, len: 6, frames: &[(0, 0), (0, 1), (0, 2), (0, 3), (0, 4), (0, 5)]},
             FighterIdle   => &AnimationData { sheet: FighterWalkSheet, len: 7, frames: &[(0, 3), (0, 3), (0, 3), (0, 6), (0, 5), (0, 5), (0, 6)]},
             FighterWalk   => &AnimationData { sheet: FighterWalkSheet, len: 8, frames: &[(0, 1), (0, 2), (0, 3), (0, 4), (0, 5), (0, 6), (0, 7), (0, 8)]},
+            // TODO ranger and mage sprites, they borrow the fighters until then
+            RangerAttack  => &AnimationData { sheet: FighterAttackSheet, len: 6, frames: &[(0, 0), (0, 1), (0, 2), (0, 3), (0, 4), (0, 5)]},
+            RangerIdle    => &AnimationData { sheet: FighterWalkSheet, len: 7, frames: &[(0, 3), (0, 3), (0, 3), (0, 6), (0, 5), (0, 5), (0, 6)]},
+            RangerWalk    => &AnimationData { sheet: FighterWalkSheet, len: 8, frames: &[(0, 1), (0, 2), (0, 3), (0, 4), (0, 5), (0, 6), (0, 7), (0, 8)]},
+            MageAttack    => &AnimationData { sheet: FighterAttackSheet, len: 6, frames: &[(0, 0), (0, 1), (0, 2), (0, 3), (0, 4), (0, 5)]},
+            MageIdle      => &AnimationData { sheet: FighterWalkSheet, len: 7, frames: &[(0, 3), (0, 3), (0, 3), (0, 6), (0, 5), (0, 5), (0, 6)]},
+            MageWalk      => &AnimationData { sheet: FighterWalkSheet, len: 8, frames: &[(0, 1), (0, 2), (0, 3), (0, 4), (0, 5), (0, 6), (0, 7), (0, 8)]},
 
             
             BatIdle         => &AnimationData { sheet: Bat, len: 4, frames: &[(0, 0), (0, 1), (0, 2), (0, 1)] },
@@ -1,7 +1,8 @@
 use std::time::Duration;
 
-use crate::item::{item::Item, item_type::ItemType};
-use crate::{dungeon::{dungeon_data::DungeonData, reward::RewardChest}, prelude::*, stash::stash::Stash, timekeeper::Timekeeper};
+use lootforge_core::item::{item::Item, item_type::ItemType};
+use lootforge_core::{dungeon::{dungeon::Area, dungeon_data::DungeonData, reward::RewardChest}, stash::stash::Stash};
+use crate::{prelude::*, timekeeper::Timekeeper};
 
 #[apply(Default)]
 pub struct CheatsWindow {
@@ -62,7 +63,10 @@ impl ChestAdder {
         ui.horizontal_wrapped(|ui| {
             ui.add(DragValue::new(&mut self.depth).range(1..=100));
             if ui.button("Add Chest").clicked() {
-                dungeon.rewards.entry(self.depth).or_default().push(RewardChest::from(&mut rand::rng(), self.depth));
+                let run = dungeon.selected();
+                let mut chest = RewardChest::from(&mut rand::rng(), &Area::new(run.area), run.modifiers, self.depth);
+                chest.wardrobe = run.wardrobe;
+                dungeon.rewards.entry(self.depth).or_default().push(chest);
             }
         });
     }
@@ -1,7 +1,9 @@
 use crate::prelude::*;
 
-use crate::combat::buff::Buffs;
-use crate::combat::combatant::{CharStats, Combatant};
+use lootforge_core::combat::buff::Buffs;
+use lootforge_core::combat::combatant::{CharStats, Combatant};
+use lootforge_core::combat::enemy::EnemyAffinity;
+use lootforge_core::elemental::{Element, Elemental};
 use crate::panels::animation::{Animation, AnimationPlayer};
 
 pub fn show_char(ui: &mut Ui, char: &Combatant, char_data: &mut CombatantData, animation_override: Option<Animation>, frame: usize, rect: Rect, align: Align) {
@@ -22,6 +24,7 @@ pub fn show_char(ui: &mut Ui, char: &Combatant, char_data: &mut CombatantData, a
 
                 if char.kind.is_enemy() {
                     show_skills_bars(ui, char);
+                    show_threat_target(ui, char);
                 } else {
                     show_skills_icons(ui, char);
                 }
@@ -117,6 +120,12 @@ fn show_skills_bars(ui: &mut Ui, char: &Combatant) {
     }
 }
 
+fn show_threat_target(ui: &mut Ui, char: &Combatant) {
+    if let Some(target) = char.threat.target() {
+        ui.label(RichText::from(format!("{:?}", target)).small().color(Color32::ORANGE));
+    }
+}
+
 fn show_tooltip(ui: &mut Ui, char: &Combatant, char_stats: &CharStats) {
     // TODO use layout job
     ui.set_min_width(200.);
@@ -130,18 +139,39 @@ fn show_tooltip(ui: &mut Ui, char: &Combatant, char_stats: &CharStats) {
         format!("HP:  {:.0} / {:.0}", char.health, char_stats.max_health)
     };
     ui.label(health);
-    ui.label(format!(
-        "Resistances: {}/{}/{}/{}",
-        char_stats.resistances.bleed,
-        char_stats.resistances.fracture,
-        char_stats.resistances.madness,
-        char_stats.resistances.void
-    ));
+    if char.wounds > 0. {
+        ui.label(format!("Wounds: {:.0}", char.wounds));
+    }
+    ui.horizontal(|ui| {
+        ui.label("Resistances:");
+        for element in Element::iter() {
+            ui.label(RichText::from(format!("{}", char_stats.resistances.get(element))).color(element.color()));
+        }
+    });
+    if let Some(affinity) = &char.affinity {
+        show_affinity(ui, affinity);
+    }
     ui.label(format!(
         "Heal Power: {}%, Shield Power: {}%",
         char_stats.heal_power * 100.,
         char_stats.shield_power * 100.
     ));
+    if char_stats.evasion > 0. {
+        ui.label(format!("Evasion: {:.0}%", char_stats.evasion * 100.));
+    }
+    if char_stats.speed != 1. {
+        ui.label(format!("Speed: {:.0}%", char_stats.speed * 100.));
+    }
+
+    if char.kind.is_enemy() {
+        let threat: Vec<_> = char.threat.iter().filter(|(_, threat)| *threat > 0.).collect();
+        if !threat.is_empty() {
+            ui.separator();
+            for (kind, threat) in threat {
+                ui.label(format!("Threat from {:?}: {:.0}", kind, threat));
+            }
+        }
+    }
 
     ui.separator();
 
@@ -149,11 +179,23 @@ fn show_tooltip(ui: &mut Ui, char: &Combatant, char_stats: &CharStats) {
     char.buffs.tooltip(ui);
 }
 
+pub fn show_affinity(ui: &mut Ui, affinity: &EnemyAffinity) {
+    ui.horizontal(|ui| {
+        ui.label("Weak to");
+        ui.label(RichText::from(format!("{:?}", affinity.weakness)).color(affinity.weakness.color()));
+        ui.label("deals");
+        ui.label(RichText::from(format!("{:?}", affinity.damage_type)).color(affinity.damage_type.color()));
+    });
+}
+
 #[apply(Default)]
 pub struct CombatantData {
     animation: Option<AnimationPlayer>,
     idle_animation_offset: u8,
     floating: Vec<FloatingText>,
+    // dots hit every tick, so they get summed up and shown once per second
+    dot: Elemental<f32>,
+    dot_ticks: u8,
 }
 #[derive(Debug, Clone)]
 struct FloatingText {
@@ -165,7 +207,7 @@ struct FloatingText {
 
 impl CombatantData {
     pub fn new(idle_animation_offset: u8) -> Self {
-        Self { animation: None, idle_animation_offset, floating: Vec::new() }
+        Self { idle_animation_offset, ..Default::default() }
     }
     pub fn tick(&mut self) {
         if let Some(animation_player) = &mut self.animation {
@@ -176,6 +218,19 @@ impl CombatantData {
 
         self.floating.iter_mut().for_each(|t| t.tick += 1);
         self.floating.retain(|t| t.tick < 8);
+
+        if self.dot.sum() > 0. {
+            self.dot_ticks += 1;
+            if self.dot_ticks >= 10 {
+                let text = RichText::from(format!("{:.0}", self.dot.sum())).color(self.dot.max_idx().color()).italics();
+                self.add_text(text);
+                self.dot = Elemental::default();
+                self.dot_ticks = 0;
+            }
+        }
+    }
+    pub fn add_dot(&mut self, damage: Elemental<f32>) {
+        self.dot = self.dot + damage;
     }
     pub fn add_animation(&mut self, animation: Animation) {
         self.animation = Some(AnimationPlayer::new(animation));
@@ -1,28 +1,42 @@
-use crate::combat::combatant::CombatantKind;
-use crate::combat::skill::skill::SkillStats;
-use crate::dungeon::dungeon_data::DungeonTick;
-use crate::dungeon::encounter::EncounterDifficulty;
-use crate::dungeon::floor::Floor;
-use crate::equipment::wardrobe::Wardrobe;
+use enumset::EnumSet;
+use lootforge_core::combat::combatant::CombatantKind;
+use lootforge_core::combat::skill::skill::SkillStats;
+use lootforge_core::dungeon::dungeon_data::DungeonTick;
+use lootforge_core::dungeon::encounter::EncounterDifficulty;
+use lootforge_core::dungeon::floor::{Floor, RoutePolicy};
+use lootforge_core::dungeon::modifier::DungeonModifier;
+use lootforge_core::dungeon::stop::{EarlyDeaths, StopReason, StopRules};
+use lootforge_core::equipment::wardrobe::Wardrobe;
 use crate::panels::animation::Animation;
 use crate::panels::dungeon::combatant::{self, CombatantData};
+use crate::panels::dungeon::log::CombatLogPanel;
+use crate::panels::dungeon::replay::{self, ReplayInput, ReplayView};
+use crate::panels::dungeon::stats::StatsPanel;
 use crate::panels::rewards::{self, RewardsWindow};
 use crate::prelude::*;
 
-use crate::{
+use lootforge_core::{
     combat::battle::Battle,
-    dungeon::dungeon_data::DungeonData,
-    timekeeper::FrameInfo,
+    dungeon::{dungeon::{Area, Background, Dungeon}, dungeon_data::DungeonData},
 };
+use crate::timekeeper::FrameInfo;
 
-#[apply(Default)]
+#[derive(Debug, SmartDefault)]
 pub struct DungeonPanel {
     battle: BattleData,
+    pub log: CombatLogPanel,
+    pub stats: StatsPanel,
+    // wardrobe set of the run the battle data belongs to
+    run: usize,
+    // shown instead of the selected run while it is open
+    pub replay: Option<ReplayView>,
 }
 
 #[apply(Default)]
 struct BattleData {
     fighter: CombatantData,
+    ranger: Option<CombatantData>,
+    mage: Option<CombatantData>,
     enemies: Vec<CombatantData>,
 }
 
@@ -33,9 +47,19 @@ impl DungeonPanel {
         frame_info: &FrameInfo,
         just_finished_loading: bool,
     ) {
-        if !dungeon.cur.finished {
-            if just_finished_loading || frame_info.catch_up.is_some() || frame_info.dungeon_tick.as_ref().is_some_and(|t| t.new_battle) {
-                self.battle = BattleData::from(&dungeon.cur.floor.battle);
+        if let Some(replay) = &mut self.replay {
+            if let ReplayInput::Tick(tick) = replay.tick(frame_info) {
+                self.battle.replay_tick(&replay.replay.dungeon.floor.battle, tick);
+            }
+            return;
+        }
+
+        let run = dungeon.selected();
+        if !run.cur.finished {
+            let switched = self.run != run.wardrobe;
+            if just_finished_loading || switched || frame_info.catch_up.is_some() || frame_info.dungeon_tick.as_ref().is_some_and(|t| t.new_battle) {
+                self.battle = BattleData::from(&run.cur.floor.battle);
+                self.run = run.wardrobe;
             }
 
             if frame_info.tick {
@@ -65,64 +89,117 @@ impl DungeonPanel {
             }
 
             ui.with_layout(Layout::right_to_left(Align::BOTTOM), |ui| {
+                let run = dungeon.selected_mut();
                 if ui.button("Cancel Run").clicked() {
-                    dungeon.cur.cancel();
+                    run.cur.cancel();
+                }
+                if ui.selectable_label(self.log.open, "Combat Log").clicked() {
+                    self.log.toggle();
+                }
+                if ui.selectable_label(self.stats.open, "Stats").clicked() {
+                    self.stats.toggle();
                 }
                 if ui
-                    .checkbox(&mut dungeon.auto_restart, "auto restart")
+                    .checkbox(&mut run.auto_restart, "auto restart")
                     .clicked()
-                    && dungeon.auto_restart
-                    && dungeon.cur.finished
+                    && run.auto_restart
+                    && run.cur.finished
                 {
-                    dungeon.restart(wardrobe.equipped());
+                    run.restart(&wardrobe.sets[run.wardrobe]);
+                }
+                show_route_selector(ui, &mut run.route_policy);
+                show_area_selector(ui, &mut run.area);
+                show_modifier_selector(ui, &mut run.modifiers);
+                show_stop_rules(ui, &mut run.stop_rules);
+                if let Some(record) = replay::show_history(ui, run) {
+                    let view = ReplayView::new(record);
+                    self.battle = BattleData::from(&view.replay.dungeon.floor.battle);
+                    self.replay = Some(view);
                 }
             });
         });
+        show_run_selector(ui, dungeon, wardrobe);
 
         ui.separator();
 
-        ScrollArea::vertical().show(ui, |ui|{
-            show_floor_info(ui, &dungeon.cur.floor);
-            
-            ui.scope(|ui| {
-                ui.style_mut().interaction.selectable_labels = false;
-                let rect = ui.add(dungeon.cur.area.background.image()).rect;
-                ui.set_clip_rect(rect);
-                
-                if dungeon.cur.finished {
-                    if show_stats(ui, rect).clicked() {
-                        dungeon.restart(wardrobe.equipped());
-                    }
-                } else {                    
-                    let transition = dungeon
-                        .cur
-                        .floor
-                        .transition
-                        .map(|f| (Floor::TRANSITION_TIME - f, frame_info.anim(ui.ctx(), 30, f, easing::linear)));
-                
-                    show_battle(
-                        ui,
-                        rect,
-                        &dungeon.cur.floor.battle,
-                        &mut self.battle,
-                        transition,
-                    );
-                };
-            
-                #[cfg(debug_assertions)]
-                ui.put(
-                    Rect::from_min_max(
-                        pos2(rect.right() - 75., rect.top()),
-                        pos2(rect.right(), rect.top() + 20.),
-                    ),
-                    Label::new(format!("{:.0} ms", frame_info.delay * 1000.)),
-                );
-                ui.force_set_min_rect(rect);
+        if let Some(replay) = &mut self.replay {
+            match replay.show(ui) {
+                ReplayInput::Tick(tick) => self.battle.replay_tick(&replay.replay.dungeon.floor.battle, tick),
+                ReplayInput::Seek => self.battle = BattleData::from(&replay.replay.dungeon.floor.battle),
+                ReplayInput::Close => {
+                    self.replay = None;
+                    // makes the next tick build the battle data of the live run again
+                    self.run = usize::MAX;
+                },
+                ReplayInput::None => {},
+            }
+        }
+
+        if let Some(replay) = &self.replay {
+            ScrollArea::vertical().show(ui, |ui| {
+                show_dungeon(ui, &replay.replay.dungeon, &mut self.battle, frame_info, true);
             });
+            return;
+        }
+
+        let run = dungeon.selected_mut();
+        let last_stop = run.last_stop;
+        let mut restart = false;
+        ScrollArea::vertical().show(ui, |ui| {
+            let rect = show_dungeon(ui, &run.cur, &mut self.battle, frame_info, !run.cur.finished);
+            if run.cur.finished {
+                restart = show_stats(ui, rect, last_stop).clicked();
+            }
         });
+        if restart {
+            run.restart(&wardrobe.sets[run.wardrobe]);
+        }
     }
 }
 
+// the floor and the battle, for the live run and replays alike. Returns the rect of the background
+fn show_dungeon(
+    ui: &mut Ui,
+    dungeon: &Dungeon,
+    battle_data: &mut BattleData,
+    frame_info: &FrameInfo,
+    draw_battle: bool,
+) -> Rect {
+    show_floor_info(ui, &dungeon.floor);
+
+    ui.scope(|ui| {
+        ui.style_mut().interaction.selectable_labels = false;
+        let rect = ui.add(dungeon.area.background.image()).rect;
+        ui.set_clip_rect(rect);
+
+        if draw_battle {
+            let transition = dungeon
+                .floor
+                .transition
+                .map(|f| (Floor::TRANSITION_TIME - f, frame_info.anim(ui.ctx(), 30, f, easing::linear)));
+
+            show_battle(
+                ui,
+                rect,
+                &dungeon.floor.battle,
+                battle_data,
+                transition,
+            );
+        }
+
+        #[cfg(debug_assertions)]
+        ui.put(
+            Rect::from_min_max(
+                pos2(rect.right() - 75., rect.top()),
+                pos2(rect.right(), rect.top() + 20.),
+            ),
+            Label::new(format!("{:.0} ms", frame_info.delay * 1000.)),
+        );
+        ui.force_set_min_rect(rect);
+        rect
+    }).inner
+}
+
 fn show_battle(
     ui: &mut Ui,
     rect: Rect,
@@ -141,6 +218,25 @@ fn show_battle(
         Rect::from_center_size(center + FIGHTER_OFFSET, vec2(64., 120.)),
         Align::LEFT,
     );
+    let backline = [
+        (&battle.ranger, &mut battle_data.ranger, Animation::RangerWalk, RANGER_OFFSET),
+        (&battle.mage, &mut battle_data.mage, Animation::MageWalk, MAGE_OFFSET),
+    ];
+    for (explorer, explorer_data, walk, offset) in backline {
+        if let (Some(explorer), Some(explorer_data)) = (explorer, explorer_data) {
+            if explorer.alive() {
+                combatant::show_char(
+                    ui,
+                    explorer,
+                    explorer_data,
+                    transition.as_ref().map(|_| walk),
+                    frame,
+                    Rect::from_center_size(center + offset, vec2(64., 120.)),
+                    Align::LEFT,
+                );
+            }
+        }
+    }
 
     let transition_offset = if let Some((_, progress)) = transition {
         vec2((1. - progress) * 250., 0.)
@@ -169,6 +265,8 @@ fn show_battle(
 }
 
 const FIGHTER_OFFSET: Vec2 = vec2(-Background::SIZE.x / 4., 40.);
+const RANGER_OFFSET: Vec2 = vec2(-90. - Background::SIZE.x / 4., -10.);
+const MAGE_OFFSET: Vec2 = vec2(-90. - Background::SIZE.x / 4., 90.);
 const ENEMY_OFFSETS: [&[Vec2]; 7] = [
     &[],
     &[vec2(Background::SIZE.x / 4., 32.)],
@@ -205,62 +303,267 @@ const ENEMY_OFFSETS: [&[Vec2]; 7] = [
 ];
 
 
-fn show_stats(ui: &mut Ui, rect: Rect) -> Response {
+fn show_stats(ui: &mut Ui, rect: Rect, last_stop: Option<StopReason>) -> Response {
+    if let Some(reason) = last_stop {
+        let rect = Rect::from_center_size(rect.center() - vec2(0., 30.), vec2(400., 20.));
+        ui.put(rect, Label::new(RichText::new(reason.describe()).color(Color32::WHITE)));
+    }
     let rect = Rect::from_center_size(rect.center(), vec2(120., 20.));
     ui.put(rect, Button::new("Start Dungeon"))
 }
 
+// every run fights with its own wardrobe set and they all keep going when not selected
+fn show_run_selector(ui: &mut Ui, dungeon: &mut DungeonData, wardrobe: &Wardrobe) {
+    ui.horizontal(|ui| {
+        for (i, run) in dungeon.runs.iter().enumerate() {
+            let text = if run.cur.finished {
+                format!("Set {}", run.wardrobe + 1)
+            } else {
+                format!("Set {} - Floor {}", run.wardrobe + 1, run.cur.floor.depth)
+            };
+            if ui.selectable_label(dungeon.selected == i, text).clicked() {
+                dungeon.selected = i;
+            }
+        }
+
+        let free: Vec<_> = dungeon.free_wardrobes(wardrobe).collect();
+        ui.add_enabled_ui(!free.is_empty(), |ui| {
+            ui.menu_button("+", |ui| {
+                for &set in &free {
+                    if ui.button(format!("Set {}", set + 1)).clicked() {
+                        dungeon.add_run(set);
+                        ui.close_menu();
+                    }
+                }
+            });
+        });
+
+        ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+            if dungeon.runs.len() > 1 && ui.button("Remove Run").on_hover_text("Chests it already found are kept").clicked() {
+                let selected = dungeon.selected;
+                dungeon.remove_run(selected);
+                return;
+            }
+            // the equipment of a running dungeon is fixed, so the set can only change in between
+            let run = dungeon.selected_mut();
+            ui.add_enabled_ui(run.cur.finished, |ui| {
+                ComboBox::from_id_salt("Wardrobe Selector")
+                    .selected_text(format!("Set {}", run.wardrobe + 1))
+                    .show_ui(ui, |ui| {
+                        for set in once(run.wardrobe).chain(free).sorted() {
+                            ui.selectable_value(&mut run.wardrobe, set, format!("Set {}", set + 1));
+                        }
+                    });
+            });
+        });
+    });
+}
+
+// only applies to the next run
+fn show_route_selector(ui: &mut Ui, selected: &mut RoutePolicy) {
+    ComboBox::from_id_salt("Route Selector")
+        .selected_text(<&'static str>::from(*selected))
+        .show_ui(ui, |ui| {
+            for policy in RoutePolicy::iter() {
+                let description = match policy {
+                    RoutePolicy::Greedy => "Fight for every chest",
+                    RoutePolicy::Safest => "Take the easiest fight at every fork",
+                    RoutePolicy::Fixed => "Never leave the main path",
+                };
+                ui.selectable_value(selected, policy, <&'static str>::from(policy))
+                    .on_hover_text(description);
+            }
+        });
+}
+
+// only applies to the next run
+fn show_area_selector(ui: &mut Ui, selected: &mut Background) {
+    ComboBox::from_id_salt("Area Selector")
+        .selected_text(<&'static str>::from(*selected))
+        .show_ui(ui, |ui| {
+            for background in Background::iter() {
+                ui.selectable_value(selected, background, <&'static str>::from(background))
+                    .on_hover_ui(|ui| show_area_info(ui, &Area::new(background)));
+            }
+        });
+}
+
+// only applies to the next run
+fn show_modifier_selector(ui: &mut Ui, selected: &mut EnumSet<DungeonModifier>) {
+    let text = if selected.is_empty() { "Modifiers".to_owned() } else { format!("Modifiers ({})", selected.len()) };
+    ui.menu_button(text, |ui| {
+        for modifier in DungeonModifier::iter() {
+            let mut active = selected.contains(modifier);
+            if ui.checkbox(&mut active, <&'static str>::from(modifier)).on_hover_text(modifier.describe()).changed() {
+                if active {
+                    selected.insert(modifier);
+                } else {
+                    selected.remove(modifier);
+                }
+            }
+        }
+    });
+}
+
+// only applies to the next run, except for early deaths which are counted over all of them
+fn show_stop_rules(ui: &mut Ui, rules: &mut StopRules) {
+    let active = [rules.depth.is_some(), rules.health.is_some(), rules.minutes.is_some(), rules.early_deaths.is_some()]
+        .into_iter()
+        .filter(|a| *a)
+        .count();
+    let text = if active == 0 { "Stop Rules".to_owned() } else { format!("Stop Rules ({})", active) };
+    ui.menu_button(text, |ui| {
+        optional_value(ui, &mut rules.depth, 10, "Retreat after clearing floor", |ui, depth| {
+            ui.add(DragValue::new(depth).range(1..=999));
+        });
+        optional_value(ui, &mut rules.health, 30, "Retreat below health", |ui, health| {
+            ui.add(DragValue::new(health).range(1..=99).suffix("%"));
+        });
+        optional_value(ui, &mut rules.minutes, 60, "Retreat after", |ui, minutes| {
+            ui.add(DragValue::new(minutes).range(1..=999).suffix(" min"));
+        });
+        optional_value(ui, &mut rules.early_deaths, EarlyDeaths { runs: 3, depth: 5 }, "Stop auto restart after", |ui, early| {
+            ui.add(DragValue::new(&mut early.runs).range(1..=99));
+            ui.label("deaths before floor");
+            ui.add(DragValue::new(&mut early.depth).range(1..=999));
+        });
+    });
+}
+
+fn optional_value<T>(ui: &mut Ui, value: &mut Option<T>, default: T, label: &str, edit: impl FnOnce(&mut Ui, &mut T)) {
+    ui.horizontal(|ui| {
+        let mut enabled = value.is_some();
+        if ui.checkbox(&mut enabled, label).changed() {
+            *value = enabled.then_some(default);
+        }
+        if let Some(value) = value {
+            edit(ui, value);
+        }
+    });
+}
+
+fn show_area_info(ui: &mut Ui, area: &Area) {
+    ui.horizontal(|ui| {
+        ui.label("Enemies deal");
+        ui.label(RichText::from(format!("{:?}", area.element)).color(area.element.color()));
+        ui.label("and are weak to");
+        ui.label(RichText::from(format!("{:?}", area.weakness)).color(area.weakness.color()));
+    });
+    ui.label(format!("Rewards lean towards {:?} mods", area.element));
+    ui.label(area.enemies.iter().map(|e| format!("{:?}", e)).join(", "));
+}
+
 fn show_floor_info(ui: &mut Ui, floor: &Floor) {
     ui.horizontal(|ui| {
         ui.label(RichText::from(format!("Floor {}:", floor.depth)).size(18.));
-        for (i, encounter) in floor.encounters.iter().enumerate() {
-            let desired_size = Vec2::splat(ui.spacing().icon_width);
-            let (rect, _) = ui.allocate_exact_size(desired_size, Sense::hover());
-            
-            let color = match encounter.difficulty {
-                EncounterDifficulty::Easy => Color32::GOLD,
-                EncounterDifficulty::Medium => Color32::ORANGE,
-                EncounterDifficulty::Hard | EncounterDifficulty::Boss => Color32::RED,
-            };
 
-            let (small_icon_rect, big_icon_rect) = ui.spacing().icon_rectangles(rect);
-            // try this instead ui.painter().rect_stroke(rect, corner_radius, stroke, stroke_kind)
-            ui.painter().add(epaint::RectShape::new(
-                big_icon_rect,
-                CornerRadius::same(2),
-                Color32::TRANSPARENT,
-                Stroke::new(1.5, color),
-                epaint::StrokeKind::Outside,
-            ));
-
-            if i < floor.battle_counter as usize - 1 {
-                ui.painter().add(Shape::line(
-                    vec![
-                        pos2(small_icon_rect.left(), small_icon_rect.center().y),
-                        pos2(small_icon_rect.center().x, small_icon_rect.bottom()),
-                        pos2(small_icon_rect.right(), small_icon_rect.top()),
-                    ],
-                    Stroke::new(1.5, Color32::WHITE),
-                ));
+        // steps go left to right, forks hang below the main path
+        let steps = floor.steps();
+        let size = ui.spacing().icon_width;
+        let gap = ui.spacing().item_spacing.x * 2.;
+        let rows = steps.iter().map(|s| s.len()).max().unwrap_or(1);
+        let desired_size = vec2(steps.len() as f32 * (size + gap), rows as f32 * (size + gap) - gap);
+        let (map_rect, _) = ui.allocate_exact_size(desired_size, Sense::hover());
+        let icon_rect = |step: usize, row: usize| Rect::from_min_size(
+            map_rect.min + vec2(step as f32 * (size + gap), row as f32 * (size + gap)),
+            Vec2::splat(size),
+        );
+
+        for (step, (from, to)) in steps.iter().tuple_windows().enumerate() {
+            for (from_row, &a) in from.iter().enumerate() {
+                for (to_row, &b) in to.iter().enumerate() {
+                    let stroke = if floor.route.contains(&a) && floor.route.contains(&b) {
+                        Stroke::new(1.5, Color32::WHITE)
+                    } else {
+                        Stroke::new(1., Color32::DARK_GRAY)
+                    };
+                    ui.painter().line_segment([icon_rect(step, from_row).right_center(), icon_rect(step + 1, to_row).left_center()], stroke);
+                }
+            }
+        }
+
+        for (step, encounters) in steps.iter().enumerate() {
+            for (row, &i) in encounters.iter().enumerate() {
+                let rect = icon_rect(step, row);
+                ui.interact(rect, ui.id().with(("encounter", i)), Sense::hover())
+                    .on_hover_ui(|ui| show_encounter_info(ui, floor, i));
+                show_encounter_icon(ui, floor, i, rect);
             }
         }
     });
 }
 
-#[apply(UnitEnum)]
-#[derive(Default)]
-pub enum Background {
-    Forest,
-    RedForest,
-    Snow,
-    Desert,
-    Cave,
-    #[default]
-    Dungeon,
+fn show_encounter_icon(ui: &mut Ui, floor: &Floor, i: usize, rect: Rect) {
+    let encounter = &floor.encounters[i];
+    let (color, fill, width) = match encounter.difficulty {
+        EncounterDifficulty::Easy => (Color32::GOLD, Color32::TRANSPARENT, 1.5),
+        EncounterDifficulty::Medium => (Color32::ORANGE, Color32::TRANSPARENT, 1.5),
+        EncounterDifficulty::Hard => (Color32::RED, Color32::TRANSPARENT, 1.5),
+        EncounterDifficulty::Boss => (Color32::from_rgb(170, 60, 255), Color32::from_rgb(60, 0, 90), 3.),
+    };
+    // the ways the route does not take fade out
+    let color = if floor.route.contains(&i) { color } else { color.gamma_multiply(0.4) };
+
+    let (small_icon_rect, big_icon_rect) = ui.spacing().icon_rectangles(rect);
+    // try this instead ui.painter().rect_stroke(rect, corner_radius, stroke, stroke_kind)
+    ui.painter().add(epaint::RectShape::new(
+        big_icon_rect,
+        CornerRadius::same(2),
+        fill,
+        Stroke::new(width, color),
+        epaint::StrokeKind::Outside,
+    ));
+    if encounter.chest {
+        ui.painter().circle_filled(big_icon_rect.right_top(), 2.5, Color32::GOLD);
+    }
+
+    if floor.route[..floor.battle_counter as usize - 1].contains(&i) {
+        ui.painter().add(Shape::line(
+            vec![
+                pos2(small_icon_rect.left(), small_icon_rect.center().y),
+                pos2(small_icon_rect.center().x, small_icon_rect.bottom()),
+                pos2(small_icon_rect.right(), small_icon_rect.top()),
+            ],
+            Stroke::new(1.5, Color32::WHITE),
+        ));
+    }
+}
+
+fn show_encounter_info(ui: &mut Ui, floor: &Floor, i: usize) {
+    let encounter = &floor.encounters[i];
+    ui.label(format!("{:?}", encounter.difficulty));
+    if encounter.chest {
+        ui.label(RichText::new("Drops a chest").color(Color32::GOLD));
+    }
+    if !floor.route.contains(&i) {
+        ui.label(RichText::new("Not on the route").weak());
+    }
+    // enemy kinds are only picked when the battle starts
+    if i == floor.current() {
+        for enemy in &floor.battle.enemies {
+            ui.horizontal(|ui| {
+                ui.label(format!("{}:", enemy.kind));
+                if let Some(affinity) = &enemy.affinity {
+                    combatant::show_affinity(ui, affinity);
+                }
+            });
+        }
+    } else {
+        for (etype, affinity) in encounter.enemies.iter().zip(&encounter.affinities) {
+            ui.horizontal(|ui| {
+                ui.label(format!("{:?}:", etype));
+                combatant::show_affinity(ui, affinity);
+            });
+        }
+    }
+}
+
+pub trait BackgroundUi {
+    const SIZE: Vec2 = vec2(/* 1104./2.*/ 552., /*621./2.*/ 310.5);
+    fn image(&self) -> Image<'_>;
 }
-impl Background {
-    pub const SIZE: Vec2 = vec2(/* 1104./2.*/ 552., /*621./2.*/ 310.5);
-    pub fn image(&self) -> Image<'_> {
+impl BackgroundUi for Background {
+    fn image(&self) -> Image<'_> {
         use Background::*;
         let source = match *self {
             Forest => include_image!("../../../assets/backgrounds/battleback1.png"),
@@ -279,6 +582,8 @@ impl BattleData {
         let mut rng = rand::rng();
         Self {
             fighter: CombatantData::new(rng.random()),
+            ranger: battle.ranger.as_ref().map(|_| CombatantData::new(rng.random())),
+            mage: battle.mage.as_ref().map(|_| CombatantData::new(rng.random())),
             enemies: battle
                 .enemies
                 .iter()
@@ -286,8 +591,17 @@ impl BattleData {
                 .collect(),
         }
     }
+    // a replay moves on tick by tick too, but new battles have to be built from it
+    fn replay_tick(&mut self, battle: &Battle, dungeon_tick: Option<DungeonTick>) {
+        if dungeon_tick.as_ref().is_some_and(|t| t.new_battle) {
+            *self = Self::from(battle);
+        }
+        self.tick(&dungeon_tick);
+    }
     fn tick(&mut self, dungeon_tick: &Option<DungeonTick>) {
         self.fighter.tick();
+        self.ranger.iter_mut().for_each(|r| r.tick());
+        self.mage.iter_mut().for_each(|m| m.tick());
         self.enemies.iter_mut().for_each(|e| e.tick());
 
         if let Some(tick) = dungeon_tick {
@@ -299,15 +613,24 @@ impl BattleData {
             SkillStats::Attack(_, attack) => {
                 match &attack.attacker {
                     CombatantKind::Fighter => self.fighter.add_animation(Animation::FighterAttack),
+                    CombatantKind::Ranger => if let Some(r) = &mut self.ranger { r.add_animation(Animation::RangerAttack) },
+                    CombatantKind::Mage => if let Some(m) = &mut self.mage { m.add_animation(Animation::MageAttack) },
                     CombatantKind::Enemy(i, enemy_kind) => self.enemies[*i as usize].add_animation(enemy_kind.attack_animation()),
                 }
                 // play attack animation of attacker
 
                 for hit in &attack.hits {
-                    let text = RichText::from(format!("{:.0}", hit.hit.post_res_dmg.sum()))
-                        .color(Color32::RED);
+                    let text = if hit.hit.evaded {
+                        RichText::from("Evade").color(Color32::LIGHT_GRAY)
+                    } else if hit.hit.crit {
+                        RichText::from(format!("{:.0}!", hit.hit.post_res_dmg.sum())).color(Color32::RED).strong()
+                    } else {
+                        RichText::from(format!("{:.0}", hit.hit.post_res_dmg.sum())).color(Color32::RED)
+                    };
                     match &hit.target {
                         CombatantKind::Fighter => self.fighter.add_text(text),
+                        CombatantKind::Ranger => if let Some(r) = &mut self.ranger { r.add_text(text) },
+                        CombatantKind::Mage => if let Some(m) = &mut self.mage { m.add_text(text) },
                         CombatantKind::Enemy(i, _) => self.enemies[*i as usize].add_text(text),
                     }
                     hit.responses.iter().for_each(|r| self.add_skill(r));
@@ -323,9 +646,38 @@ impl BattleData {
                 let text = RichText::from(format!("{:.0}", total)).color(color);
                 match &def_stats.defender {
                     CombatantKind::Fighter => self.fighter.add_text(text),
+                    CombatantKind::Ranger => if let Some(r) = &mut self.ranger { r.add_text(text) },
+                    CombatantKind::Mage => if let Some(m) = &mut self.mage { m.add_text(text) },
                     CombatantKind::Enemy(i, _) => self.enemies[*i as usize].add_text(text),
                 }
             }
+            SkillStats::Support(_, support_stats) => {
+                let total = support_stats.healed + support_stats.shielded;
+                // pure buffs have no number to show
+                if total <= 0. {
+                    return;
+                }
+                let color = if support_stats.healed > 0. {
+                    Color32::GREEN
+                } else {
+                    Color32::LIGHT_GRAY
+                };
+                let text = RichText::from(format!("{:.0}", total)).color(color);
+                match &support_stats.target {
+                    CombatantKind::Fighter => self.fighter.add_text(text),
+                    CombatantKind::Ranger => if let Some(r) = &mut self.ranger { r.add_text(text) },
+                    CombatantKind::Mage => if let Some(m) = &mut self.mage { m.add_text(text) },
+                    CombatantKind::Enemy(i, _) => self.enemies[*i as usize].add_text(text),
+                }
+            }
+            SkillStats::Dot(_, dot) => {
+                match &dot.target {
+                    CombatantKind::Fighter => self.fighter.add_dot(dot.damage),
+                    CombatantKind::Ranger => if let Some(r) = &mut self.ranger { r.add_dot(dot.damage) },
+                    CombatantKind::Mage => if let Some(m) = &mut self.mage { m.add_dot(dot.damage) },
+                    CombatantKind::Enemy(i, _) => self.enemies[*i as usize].add_dot(dot.damage),
+                }
+            }
         }
     }
 }
@@ -0,0 +1,89 @@
+use lootforge_core::combat::{battle::Battle, event::{CombatEvent, EventCategory}};
+use crate::prelude::*;
+
+#[apply(Default)]
+pub struct CombatLogPanel {
+    pub open: bool,
+    hidden: Vec<EventCategory>,
+}
+
+impl CombatLogPanel {
+    pub fn toggle(&mut self) {
+        self.open = !self.open;
+    }
+
+    pub fn show(&mut self, ctx: &Context, battle: &Battle) {
+        if !self.open {
+            return;
+        }
+        SidePanel::right("combat log")
+            .resizable(true)
+            .default_width(300.)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.heading("Combat Log");
+                    ui.with_layout(Layout::right_to_left(Align::BOTTOM), |ui| {
+                        if ui.button("Close").clicked() {
+                            self.open = false;
+                        }
+                    });
+                });
+
+                ui.horizontal_wrapped(|ui| {
+                    for category in EventCategory::iter() {
+                        let mut shown = !self.hidden.contains(&category);
+                        if ui.checkbox(&mut shown, <&'static str>::from(category)).changed() {
+                            if shown {
+                                self.hidden.retain(|c| *c != category);
+                            } else {
+                                self.hidden.push(category);
+                            }
+                        }
+                    }
+                });
+
+                let events: Vec<_> = battle.log.iter()
+                    .filter(|(_, e)| !self.hidden.contains(&e.category()))
+                    .collect();
+
+                ui.horizontal(|ui| {
+                    if ui.button("Copy Text").clicked() {
+                        ui.ctx().copy_text(events.iter().map(|(tick, e)| format!("{} {}", time(*tick), e)).join("\n"));
+                    }
+                    if ui.button("Copy CSV").clicked() {
+                        let rows = events.iter().map(|(tick, e)| e.csv(*tick));
+                        ui.ctx().copy_text(once(CombatEvent::CSV_HEADER.to_string()).chain(rows).join("\n"));
+                    }
+                    ui.label(format!("{} events", events.len()));
+                });
+                ui.separator();
+
+                let row_height = ui.text_style_height(&TextStyle::Body);
+                ScrollArea::vertical()
+                    .auto_shrink(false)
+                    .stick_to_bottom(true)
+                    .show_rows(ui, row_height, events.len(), |ui, rows| {
+                        for (tick, event) in &events[rows] {
+                            ui.horizontal(|ui| {
+                                ui.label(RichText::new(time(*tick)).weak());
+                                ui.label(RichText::new(event.to_string()).color(color(event.category())));
+                            });
+                        }
+                    });
+            });
+    }
+}
+
+fn time(tick: u64) -> String {
+    format!("{:.1}s", tick as f32 / 10.)
+}
+
+fn color(category: EventCategory) -> Color32 {
+    match category {
+        EventCategory::Damage => Color32::LIGHT_GRAY,
+        EventCategory::Defense => Color32::LIGHT_BLUE,
+        EventCategory::Dot => Color32::LIGHT_RED,
+        EventCategory::Buff => Color32::LIGHT_YELLOW,
+        EventCategory::Death => Color32::RED,
+    }
+}
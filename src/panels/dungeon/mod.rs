@@ -1,3 +1,6 @@
 pub mod dungeon;
+pub mod log;
+pub mod replay;
+pub mod stats;
 
 mod combatant;
\ No newline at end of file
@@ -0,0 +1,119 @@
+use std::rc::Rc;
+
+use lootforge_core::dungeon::{dungeon_data::{DungeonRun, DungeonTick}, replay::{Replay, RunRecord}};
+use crate::prelude::*;
+use crate::timekeeper::FrameInfo;
+
+// a finished run simulated again from its seed, so it plays out exactly like it did
+#[derive(Debug)]
+pub struct ReplayView {
+    pub replay: Replay,
+    playing: bool,
+    // where the slider is while it is dragged, the replay only seeks once it is let go
+    scrub: Option<u64>,
+}
+
+#[apply(Enum)]
+pub enum ReplayInput {
+    None,
+    // moved on by one tick, just like the live run does
+    Tick(Option<DungeonTick>),
+    // jumped somewhere else, everything shown has to be built again
+    Seek,
+    Close,
+}
+
+impl ReplayView {
+    pub fn new(record: Rc<RunRecord>) -> Self {
+        Self { replay: Replay::new(record), playing: true, scrub: None }
+    }
+
+    pub fn tick(&mut self, frame_info: &FrameInfo) -> ReplayInput {
+        self.replay.scout();
+        if !self.playing || !frame_info.tick {
+            return ReplayInput::None;
+        }
+        if self.replay.at_end() {
+            self.playing = false;
+            return ReplayInput::None;
+        }
+        ReplayInput::Tick(self.replay.step())
+    }
+
+    pub fn show(&mut self, ui: &mut Ui) -> ReplayInput {
+        let mut input = ReplayInput::None;
+        let record = self.replay.record.clone();
+
+        ui.horizontal(|ui| {
+            ui.label(RichText::new(format!("Replay: {}", record_name(&record))).strong());
+            ui.with_layout(Layout::right_to_left(Align::Center), |ui| {
+                if ui.button("Back to Run").clicked() {
+                    input = ReplayInput::Close;
+                }
+            });
+        });
+
+        ui.horizontal(|ui| {
+            let at_end = self.replay.at_end();
+            if ui.add_enabled(!at_end, Button::new(if self.playing { "Pause" } else { "Play" })).clicked() {
+                self.playing = !self.playing;
+            }
+            if ui.add_enabled(!self.playing && !at_end, Button::new("Step")).clicked() {
+                input = ReplayInput::Tick(self.replay.step());
+            }
+
+            let battles = &self.replay.battles;
+            let current = battles.iter().rposition(|(tick, _)| *tick <= self.replay.dungeon.tick).unwrap_or(0);
+            let mut jump = None;
+            ComboBox::from_id_salt("Replay Battle")
+                .selected_text(format!("Battle {}", current + 1))
+                .show_ui(ui, |ui| {
+                    for (i, &(tick, depth)) in battles.iter().enumerate() {
+                        if ui.selectable_label(i == current, format!("Battle {} - Floor {}", i + 1, depth)).clicked() {
+                            jump = Some(tick);
+                        }
+                    }
+                });
+            if let Some(tick) = jump {
+                self.scrub = None;
+                self.replay.seek(tick);
+                input = ReplayInput::Seek;
+            }
+
+            let mut tick = self.scrub.unwrap_or(self.replay.dungeon.tick);
+            let response = ui.add(Slider::new(&mut tick, 0..=record.ticks).text("tick"));
+            if response.dragged() {
+                self.scrub = Some(tick);
+            } else if response.changed() || response.drag_stopped() {
+                self.scrub = None;
+                self.replay.seek(tick);
+                input = ReplayInput::Seek;
+            }
+        });
+
+        input
+    }
+}
+
+// only finished runs are in the history, newest first
+pub fn show_history(ui: &mut Ui, run: &DungeonRun) -> Option<Rc<RunRecord>> {
+    let mut picked = None;
+    ui.add_enabled_ui(!run.history.is_empty(), |ui| {
+        ui.menu_button("Replays", |ui| {
+            for record in &run.history {
+                if ui.button(record_name(record)).clicked() {
+                    picked = Some(record.clone());
+                    ui.close_menu();
+                }
+            }
+        });
+    });
+    picked
+}
+
+fn record_name(record: &RunRecord) -> String {
+    match record.stop_reason {
+        Some(reason) => format!("Floor {} - {}", record.depth, reason.describe()),
+        None => format!("Floor {}", record.depth),
+    }
+}
@@ -0,0 +1,138 @@
+use lootforge_core::{
+    combat::{combatant::CombatantKind, skill::skill::SkillSource},
+    dungeon::{dungeon_data::DungeonRun, stats::CombatStats},
+    elemental::Element,
+    mods::roll_tables::ALL_MODS,
+};
+use crate::prelude::*;
+
+#[apply(UnitEnum)]
+#[derive(Default)]
+enum StatsScope {
+    #[default]
+    Battle,
+    Run,
+    LastRun,
+}
+
+#[apply(Default)]
+pub struct StatsPanel {
+    pub open: bool,
+    scope: StatsScope,
+}
+
+impl StatsPanel {
+    pub fn toggle(&mut self) {
+        self.open = !self.open;
+    }
+
+    pub fn show(&mut self, ctx: &Context, dungeon: &DungeonRun) {
+        if !self.open {
+            return;
+        }
+        SidePanel::right("run stats")
+            .resizable(true)
+            .default_width(300.)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.heading("Stats");
+                    ui.with_layout(Layout::right_to_left(Align::BOTTOM), |ui| {
+                        if ui.button("Close").clicked() {
+                            self.open = false;
+                        }
+                    });
+                });
+                ui.horizontal(|ui| {
+                    ui.selectable_value(&mut self.scope, StatsScope::Battle, "Battle");
+                    ui.selectable_value(&mut self.scope, StatsScope::Run, "Run");
+                    ui.add_enabled_ui(dungeon.last_run.is_some(), |ui| {
+                        ui.selectable_value(&mut self.scope, StatsScope::LastRun, "Last Run");
+                    });
+                });
+                ui.separator();
+
+                let stats = match self.scope {
+                    StatsScope::Battle => Some(&dungeon.cur.battle_stats),
+                    StatsScope::Run => Some(&dungeon.cur.run_stats),
+                    StatsScope::LastRun => dungeon.last_run.as_ref(),
+                };
+                if let Some(stats) = stats {
+                    ScrollArea::vertical().auto_shrink(false).show(ui, |ui| show_stats(ui, stats));
+                }
+            });
+    }
+}
+
+fn show_stats(ui: &mut Ui, stats: &CombatStats) {
+    ui.label(RichText::new(format!("Damage dealt: {:.0}", stats.total_dealt())).heading().size(18.));
+    let mut dealt: Vec<_> = stats.dealt.iter().collect();
+    dealt.sort_by_key(|d| std::cmp::Reverse(F32Ord(d.damage.sum())));
+    let mut dot_dealt: Vec<_> = stats.dot_dealt.iter().collect();
+    dot_dealt.sort_by_key(|d| std::cmp::Reverse(F32Ord(d.damage.sum())));
+    let max = dealt.first().map_or(0., |d| d.damage.sum())
+        .max(dot_dealt.first().map_or(0., |d| d.damage.sum()));
+
+    for skill in dealt {
+        let name = skill_name(skill.user, &skill.source);
+        let segments = Element::iter().map(|e| (*skill.damage.get(e), e.color()));
+        damage_bar(ui, name, segments, max);
+    }
+    for dot in dot_dealt {
+        let name = match dot.mod_id {
+            Some(mod_id) => format!("{:?} from {} ({})", dot.debuff, skill_name(dot.user, &dot.source), ALL_MODS[&mod_id].prefix_name),
+            None => format!("{:?} from {}", dot.debuff, skill_name(dot.user, &dot.source)),
+        };
+        let segments = Element::iter().map(|e| (*dot.damage.get(e), e.color().gamma_multiply(0.6)));
+        damage_bar(ui, name, segments, max);
+    }
+
+    ui.separator();
+    ui.label(RichText::new(format!("Damage taken: {:.0}", stats.total_taken())).heading().size(18.));
+    ui.horizontal_wrapped(|ui| {
+        for element in Element::iter() {
+            ui.label(RichText::new(format!("{:.0}", stats.taken.get(element))).color(element.color()));
+        }
+        ui.label(format!("{:.0} over time", stats.dot_taken));
+    });
+    ui.label(format!("Mitigated by resistances: {:.0}", stats.mitigated_by_res));
+    ui.label(format!("Absorbed by shields: {:.0}", stats.absorbed_by_shield));
+
+    ui.separator();
+    ui.label(format!("Healed: {:.0}", stats.healed));
+    ui.label(format!("Shielded: {:.0}", stats.shielded));
+    ui.label(format!("Blocks: {}", stats.blocks));
+    ui.label(format!("Evades: {}", stats.evades));
+    ui.label(format!("Crits: {}", stats.crits));
+    ui.label(format!("Culls: {}", stats.culls));
+
+    ui.separator();
+    ui.label(RichText::new(format!("Kills: {}", stats.kills.iter().map(|(_, k)| k).sum::<u32>())).heading().size(18.));
+    for (kind, kills) in &stats.kills {
+        ui.label(format!("{:?}: {}", kind, kills));
+    }
+}
+
+fn skill_name(user: CombatantKind, source: &SkillSource) -> String {
+    match source {
+        SkillSource::Item { item_type, .. } => format!("{} {:?}", user, item_type),
+        SkillSource::Enemy { kind, .. } => format!("{} {:?}", user, kind),
+    }
+}
+
+// one bar per damage source, split into colored segments like the elements of a hit
+fn damage_bar(ui: &mut Ui, name: String, segments: impl Iterator<Item = (f32, Color32)> + Clone, max: f32) {
+    ui.horizontal(|ui| {
+        ui.label(format!("{:.0}", segments.clone().map(|(damage, _)| damage).sum::<f32>()));
+        ui.label(name);
+    });
+    let width = ui.available_width();
+    let (rect, _) = ui.allocate_exact_size(vec2(width, 6.), Sense::hover());
+    ui.painter().rect_filled(rect, 0., ui.visuals().extreme_bg_color);
+    let mut left = rect.left();
+    for (damage, color) in segments {
+        let w = if max > 0. { width * damage / max } else { 0. };
+        let segment = Rect::from_min_size(pos2(left, rect.top()), vec2(w, rect.height()));
+        ui.painter().rect_filled(segment, 0., color);
+        left += w;
+    }
+}
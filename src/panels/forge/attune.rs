@@ -2,15 +2,15 @@ use std::iter;
 
 use enumset::EnumSet;
 
-use crate::item::tags::Rating;
-use crate::mods::attune::AttuneGroup;
-use crate::mods::RolledMod;
+use lootforge_core::item::tags::Rating;
+use lootforge_core::mods::attune::AttuneGroup;
+use lootforge_core::mods::RolledMod;
 use crate::prelude::*;
 use super::common::*;
 
-use crate::item::{item::Item, item::ItemRef};
-use crate::stash::filters::ItemFilter;
-use crate::stash::stash::Stash;
+use lootforge_core::item::{item::Item, item::ItemRef};
+use lootforge_core::stash::filters::ItemFilter;
+use lootforge_core::stash::stash::Stash;
 
 
 #[apply(Default)]
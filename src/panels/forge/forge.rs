@@ -4,13 +4,10 @@ use crate::panels::forge::reroll_target::RerollTarget;
 use crate::panels::forge::upgrade::Upgrade;
 use crate::prelude::*;
 
-use crate::stash::filters::ItemFilter;
+use lootforge_core::stash::filters::ItemFilter;
 use crate::widgets::selectable_image::SelectableImage;
-use crate::{
-    item::item::ItemRef, stash::stash::Stash, widgets::{
-        selectable_image::UiSelectableImage,
-    }
-};
+use lootforge_core::{item::item::ItemRef, stash::stash::Stash};
+use crate::widgets::selectable_image::UiSelectableImage;
 
 #[apply(Default)]
 pub struct ForgePanel {
@@ -2,14 +2,14 @@ use std::iter;
 
 use enumset::EnumSet;
 
-use crate::item::tags::Rating;
-use crate::mods::roll_tables::ALL_MODS;
+use lootforge_core::item::tags::Rating;
+use lootforge_core::mods::roll_tables::ALL_MODS;
 use crate::prelude::*;
 use super::common::*;
 
-use crate::item::{item::Item, item::ItemRef};
-use crate::stash::filters::ItemFilter;
-use crate::stash::stash::Stash;
+use lootforge_core::item::{item::Item, item::ItemRef};
+use lootforge_core::stash::filters::ItemFilter;
+use lootforge_core::stash::stash::Stash;
 
 #[apply(Default)]
 pub struct Upgrade {
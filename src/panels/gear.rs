@@ -1,4 +1,5 @@
-use crate::{equipment::{equipment::{Equip, EquipChange, FighterEquip}, wardrobe::{ItemSlot, Wardrobe}}, explorer::Explorer, item::item::ItemRef, prelude::*, stash::stash::Stash, widgets::item_drop_zone::item_drop_zone};
+use lootforge_core::{equipment::{equipment::{Equip, EquipChange, FighterEquip, MageEquip, RangerEquip}, wardrobe::{ItemSlot, Wardrobe}}, explorer::Explorer, item::item::ItemRef, stash::stash::Stash};
+use crate::{prelude::*, widgets::item_drop_zone::item_drop_zone};
 
 #[apply(Default)]
 pub struct GearPanel {
@@ -33,9 +34,29 @@ impl GearPanel {
             wardrobe.set_equipped(self.open);
         }
 
-        // TODO add unequip logic for things that can be equipped by other chars
-        let changes = self.show_fighter_grid(ui, &mut wardrobe.sets[self.open].fighter_equip, stash);
-        self.update_changes(changes, wardrobe.is_equipped(self.open));
+        let is_equipped = wardrobe.is_equipped(self.open);
+        let set = &mut wardrobe.sets[self.open];
+
+        ScrollArea::vertical().show(ui, |ui| {
+            let changes = self.show_fighter_grid(ui, &mut set.fighter_equip, stash);
+            unequip_common(&mut set.ranger_equip, &BACKLINE_COMMON_SLOTS, &changes);
+            unequip_common(&mut set.mage_equip, &BACKLINE_COMMON_SLOTS, &changes);
+            self.update_changes(changes, is_equipped);
+
+            ui.separator();
+
+            let changes = self.show_ranger_grid(ui, &mut set.ranger_equip, stash);
+            unequip_common(&mut set.fighter_equip, &FIGHTER_COMMON_SLOTS, &changes);
+            unequip_common(&mut set.mage_equip, &BACKLINE_COMMON_SLOTS, &changes);
+            self.update_changes(changes, is_equipped);
+
+            ui.separator();
+
+            let changes = self.show_mage_grid(ui, &mut set.mage_equip, stash);
+            unequip_common(&mut set.fighter_equip, &FIGHTER_COMMON_SLOTS, &changes);
+            unequip_common(&mut set.ranger_equip, &BACKLINE_COMMON_SLOTS, &changes);
+            self.update_changes(changes, is_equipped);
+        });
 
         // TODO calc some stats and show them
     }
@@ -49,20 +70,20 @@ impl GearPanel {
             .show(ui, |ui| {
                 ui.label("");
                 ui.label("");
-                show_item_slot_fighter(ui, stash, ItemSlot::Helmet, equip, &mut changes);
+                show_item_slot(ui, stash, Explorer::Fighter, ItemSlot::Helmet, equip, &mut changes);
                 ui.label("");
                 ui.end_row();
     
-                let w1 = show_item_slot_fighter(ui, stash, ItemSlot::Weapon(0), equip, &mut changes);
-                let w2 = show_item_slot_fighter(ui, stash, ItemSlot::Weapon(1), equip, &mut changes);
-                show_item_slot_fighter(ui, stash, ItemSlot::Armor, equip, &mut changes);
-                let s = show_item_slot_fighter(ui, stash, ItemSlot::FighterShield, equip, &mut changes);
+                let w1 = show_item_slot(ui, stash, Explorer::Fighter, ItemSlot::Weapon(0), equip, &mut changes);
+                let w2 = show_item_slot(ui, stash, Explorer::Fighter, ItemSlot::Weapon(1), equip, &mut changes);
+                show_item_slot(ui, stash, Explorer::Fighter, ItemSlot::Armor, equip, &mut changes);
+                let s = show_item_slot(ui, stash, Explorer::Fighter, ItemSlot::FighterShield, equip, &mut changes);
                 ui.end_row();
     
-                let r1 = show_item_slot_fighter(ui, stash, ItemSlot::Ring(0), equip, &mut changes);
-                let r2 = show_item_slot_fighter(ui, stash, ItemSlot::Ring(1), equip, &mut changes);
-                show_item_slot_fighter(ui, stash, ItemSlot::Gloves, equip, &mut changes);
-                let r3 = show_item_slot_fighter(ui, stash, ItemSlot::Ring(2), equip, &mut changes);
+                let r1 = show_item_slot(ui, stash, Explorer::Fighter, ItemSlot::Ring(0), equip, &mut changes);
+                let r2 = show_item_slot(ui, stash, Explorer::Fighter, ItemSlot::Ring(1), equip, &mut changes);
+                show_item_slot(ui, stash, Explorer::Fighter, ItemSlot::Gloves, equip, &mut changes);
+                let r3 = show_item_slot(ui, stash, Explorer::Fighter, ItemSlot::Ring(2), equip, &mut changes);
                 ui.end_row();
                 [(w1, r1), (w2, r2),  (s, r3)]
             }).inner;
@@ -75,6 +96,70 @@ impl GearPanel {
         changes
     }
 
+    fn show_ranger_grid(&mut self, ui: &mut Ui, equip: &mut RangerEquip, stash: &Stash) -> Vec<EquipChange> {
+        let mut changes = vec![];
+        let responses = egui::Grid::new("ranger_gear_equip_grid")
+            .min_col_width(64.)
+            .min_row_height(64.)
+            .spacing([5., 5.])
+            .show(ui, |ui| {
+                ui.label("");
+                ui.label("");
+                show_item_slot(ui, stash, Explorer::Ranger, ItemSlot::Helmet, equip, &mut changes);
+                show_item_slot(ui, stash, Explorer::Ranger, ItemSlot::RangerSatchel, equip, &mut changes);
+                ui.end_row();
+
+                let w1 = show_item_slot(ui, stash, Explorer::Ranger, ItemSlot::Weapon(0), equip, &mut changes);
+                let w2 = show_item_slot(ui, stash, Explorer::Ranger, ItemSlot::Weapon(1), equip, &mut changes);
+                show_item_slot(ui, stash, Explorer::Ranger, ItemSlot::Armor, equip, &mut changes);
+                show_item_slot(ui, stash, Explorer::Ranger, ItemSlot::RangerQuiver, equip, &mut changes);
+                ui.end_row();
+
+                let r1 = show_item_slot(ui, stash, Explorer::Ranger, ItemSlot::Ring(0), equip, &mut changes);
+                let r2 = show_item_slot(ui, stash, Explorer::Ranger, ItemSlot::Ring(1), equip, &mut changes);
+                show_item_slot(ui, stash, Explorer::Ranger, ItemSlot::Gloves, equip, &mut changes);
+                ui.end_row();
+                [(w1, r1), (w2, r2)]
+            }).inner;
+
+            connect_slots(ui, equip.weapons[0].clone(), equip.common.rings[0].clone(), &responses[0].0, &responses[0].1);
+            connect_slots(ui, equip.weapons[1].clone(), equip.common.rings[1].clone(), &responses[1].0, &responses[1].1);
+
+        changes
+    }
+
+    fn show_mage_grid(&mut self, ui: &mut Ui, equip: &mut MageEquip, stash: &Stash) -> Vec<EquipChange> {
+        let mut changes = vec![];
+        let responses = egui::Grid::new("mage_gear_equip_grid")
+            .min_col_width(64.)
+            .min_row_height(64.)
+            .spacing([5., 5.])
+            .show(ui, |ui| {
+                ui.label("");
+                ui.label("");
+                show_item_slot(ui, stash, Explorer::Mage, ItemSlot::Helmet, equip, &mut changes);
+                show_item_slot(ui, stash, Explorer::Mage, ItemSlot::MageSupportGem, equip, &mut changes);
+                ui.end_row();
+
+                let w1 = show_item_slot(ui, stash, Explorer::Mage, ItemSlot::Weapon(0), equip, &mut changes);
+                let w2 = show_item_slot(ui, stash, Explorer::Mage, ItemSlot::Weapon(1), equip, &mut changes);
+                show_item_slot(ui, stash, Explorer::Mage, ItemSlot::Armor, equip, &mut changes);
+                show_item_slot(ui, stash, Explorer::Mage, ItemSlot::MageStaff, equip, &mut changes);
+                ui.end_row();
+
+                let r1 = show_item_slot(ui, stash, Explorer::Mage, ItemSlot::Ring(0), equip, &mut changes);
+                let r2 = show_item_slot(ui, stash, Explorer::Mage, ItemSlot::Ring(1), equip, &mut changes);
+                show_item_slot(ui, stash, Explorer::Mage, ItemSlot::Gloves, equip, &mut changes);
+                ui.end_row();
+                [(w1, r1), (w2, r2)]
+            }).inner;
+
+            connect_slots(ui, equip.weapons[0].clone(), equip.common.rings[0].clone(), &responses[0].0, &responses[0].1);
+            connect_slots(ui, equip.weapons[1].clone(), equip.common.rings[1].clone(), &responses[1].0, &responses[1].1);
+
+        changes
+    }
+
     fn update_changes(&self, changes: Vec<EquipChange>, is_equipped: bool) {
         for change in changes {
             if let Some(item) = change.added.upgrade() {
@@ -96,18 +181,18 @@ impl GearPanel {
 
 }
 
-// TODO don't need copies for other explorer, this is generelizable
-fn show_item_slot_fighter(
+fn show_item_slot(
     ui: &mut Ui,
     stash: &Stash,
+    explorer: Explorer,
     slot: ItemSlot,
-    equip: &mut FighterEquip,
+    equip: &mut impl Equip,
     changes: &mut Vec<EquipChange>,
 ) -> Response {
     let (item, offhand) = equip.get_item(slot);
     let tint = if offhand {Color32::GRAY} else {Color32::WHITE};
 
-    let (response, dropped_item) = item_drop_zone(ui, stash, |item| slot.accepts(Explorer::Fighter, item), |ui| {
+    let (response, dropped_item) = item_drop_zone(ui, stash, |item| slot.accepts(explorer, item), |ui| {
         ui.set_min_size(vec2(64., 64.));
 
         if let Some(item) = item.upgrade() {
@@ -116,7 +201,7 @@ fn show_item_slot_fighter(
                 equip.set_item(ItemRef::new(), slot).map(|c| changes.push(c));
             }
                 response.on_hover_ui(|ui| item.tooltip(ui));
-        } else if let Some(default) = slot.default_type(Explorer::Fighter) {
+        } else if let Some(default) = slot.default_type(explorer) {
             ui.add(default.image().tint(Color32::DARK_GRAY));
         }
     });
@@ -128,6 +213,20 @@ fn show_item_slot_fighter(
     response.response
 }
 
+const FIGHTER_COMMON_SLOTS: [ItemSlot; 6] = [ItemSlot::Helmet, ItemSlot::Armor, ItemSlot::Gloves, ItemSlot::Ring(0), ItemSlot::Ring(1), ItemSlot::Ring(2)];
+// ranger and mage only have 2 rings
+const BACKLINE_COMMON_SLOTS: [ItemSlot; 5] = [ItemSlot::Helmet, ItemSlot::Armor, ItemSlot::Gloves, ItemSlot::Ring(0), ItemSlot::Ring(1)];
+
+// common items can be worn by every explorer, but only by one of them per set
+// the item stays in the set, so the wardrobe tags don't change
+fn unequip_common(other: &mut impl Equip, slots: &[ItemSlot], changes: &[EquipChange]) {
+    for change in changes.iter().filter(|c| c.added.upgrade().is_some()) {
+        if let Some(&slot) = slots.iter().find(|&&slot| other.get_item(slot).0.ptr_eq(&change.added)) {
+            other.set_item(ItemRef::new(), slot);
+        }
+    }
+}
+
 fn connect_slots(ui: &mut Ui, item1: ItemRef, item2: ItemRef, response1: &Response, response2: &Response) {
     if item1.upgrade().is_none() || item2.upgrade().is_none() {
         return;
@@ -1,12 +1,12 @@
 use egui_double_slider::DoubleSlider;
 
-use crate::item::item_type::ItemType;
-use crate::item::tags::Rating;
-use crate::mods::{roll_tables, ModType};
+use lootforge_core::item::item_type::ItemType;
+use lootforge_core::item::tags::Rating;
+use lootforge_core::mods::{roll_tables, ModType};
 use crate::prelude::*;
-use crate::stash::filters::ItemFilter;
-use crate::stash::stash::Stash;
-use crate::stash::order::Order;
+use lootforge_core::stash::filters::ItemFilter;
+use lootforge_core::stash::stash::Stash;
+use lootforge_core::stash::order::Order;
 use crate::widgets::selectable_image::SelectableImage;
 
 #[apply(Default)]
@@ -114,7 +114,7 @@ impl LootPanel {
 
                     response.on_hover_ui(|ui| {
                         // TODO make a tooltip variant without roll          
-                        (item_mod.show_tooltip)(item_mod, ui, *item_mod.roll_range.end());
+                        item_mod.show_tooltip(ui, *item_mod.roll_range.end());
                     });
                 }
             });
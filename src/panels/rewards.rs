@@ -1,17 +1,18 @@
 use std::f32::consts::PI;
 
-use crate::{
+use lootforge_core::{
     dungeon::reward::RewardChest,
     item::{item::Item, item_type::ItemType},
-    prelude::*,
-    stash::stash::Stash, widgets::text_in_rect::text_in_rect,
+    stash::stash::Stash,
 };
+use crate::{prelude::*, widgets::text_in_rect::text_in_rect};
 use egui::{emath::inverse_lerp, epaint::RectShape};
 use rand::distr::Uniform;
 use rand_chacha::ChaCha12Rng;
 use web_time::SystemTime;
 
-use crate::{dungeon::dungeon_data::DungeonData, widgets::selectable_image::SelectableImage};
+use lootforge_core::dungeon::dungeon_data::DungeonData;
+use crate::widgets::selectable_image::SelectableImage;
 
 #[derive(Debug, SmartDefault)]
 pub struct RewardsWindow {
@@ -109,7 +110,7 @@ fn draw_row(ui: &mut Ui, depth: u16, rewards: &Vec<RewardChest>, dont_draw_idx:
             if dont_draw_idx.contains(&i) {
                 add_chest_button_placeholder(ui);
             } else {                
-                let response = add_chest_button(ui);
+                let response = add_chest_button(ui).on_hover_text(format!("Found with set {}", reward.wardrobe + 1));
                 if response.clicked() {
                     opening = Some(ChestOpening {
                         start: SystemTime::now(),
@@ -22,6 +22,7 @@ pub use std::rc::{Rc, Weak};
 pub use std::cell::{Cell, RefCell};
 
 pub use lootforge_macros::*;
-pub use crate::util::*;
+pub use lootforge_core::util::{F32Ord, SplitOption, IndexedRandomNonEmpty, IteratorRandomNonEmpty};
+pub use crate::visuals::*;
 
 pub use log::{info, warn, error};
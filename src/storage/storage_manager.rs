@@ -1,6 +1,11 @@
+use std::time::Duration;
+
+use lootforge_core::storage::ser::ValidatedBytes;
+use lootforge_core::storage::{ser_v1, ser_v2};
+use web_time::SystemTime;
+
 use crate::prelude::*;
-use crate::storage::ser::ValidatedBytes;
-use crate::{storage::ser_v1, LootforgeApp};
+use crate::{timekeeper::Timekeeper, LootforgeApp};
 
 #[apply(Enum)]
 #[derive(PartialEq)]
@@ -76,7 +81,7 @@ impl StorageManager {
     }
 
     fn save_app(file_name: String, app: &mut LootforgeApp, epoch_millis: u64) {
-        let bytes = ser_v1::ser(app, epoch_millis);
+        let bytes = ser_v2::ser(&app.stash, &app.wardrobe, &app.dungeon, epoch_millis);
         app.storage_manager.save_bytes(file_name, bytes);
     }
     
@@ -143,9 +148,22 @@ impl StorageManager {
             .filter_map(|bytes| ValidatedBytes::validate(bytes))
             .max_by_key(|v| (v.version, v.ts))
             .and_then(|v| match v.version {
-                1 => ser_v1::deser(self.clone(), &v.bytes),
+                1 => ser_v1::deser(&v.bytes),
+                2 => ser_v2::deser(&v.bytes),
                 _ => panic!(),
             })
+            .map(|save| {
+                let mut timekeeper = Timekeeper::default();
+                timekeeper.last_save_sim = SystemTime::UNIX_EPOCH + Duration::from_millis(save.epoch_millis);
+                LootforgeApp {
+                    timekeeper,
+                    storage_manager: self.clone(),
+                    stash: save.stash,
+                    wardrobe: save.wardrobe,
+                    dungeon: save.dungeon,
+                    ..Default::default()
+                }
+            })
     }
 
     fn manage_saves(&mut self) {}
@@ -1,7 +1,8 @@
 use core::f64;
 use std::time::Duration;
 
-use crate::{dungeon::dungeon_data::DungeonTick, prelude::*};
+use lootforge_core::dungeon::dungeon_data::DungeonTick;
+use crate::prelude::*;
 use web_time::SystemTime;
 use derive_more::Debug;
 
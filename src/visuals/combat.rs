@@ -0,0 +1,142 @@
+use lootforge_core::combat::{
+    buff::{BuffIcon, Buffs},
+    combatant::{Combatant, CombatantKind},
+    enemy::EnemyKind,
+    skill::skill::{Skill, SkillSource},
+};
+
+use crate::panels::animation::{Animation, Spritesheet};
+use crate::prelude::*;
+
+pub trait BuffsUi {
+    fn tooltip(&self, ui: &mut Ui);
+}
+impl BuffsUi for Buffs {
+    fn tooltip(&self, ui: &mut Ui) {
+        for (icon, description) in self.descriptions() {
+            ui.horizontal_wrapped(|ui| {
+                ui.add(icon.image());
+                ui.label(description);
+            });
+        }
+    }
+}
+
+pub trait BuffIconUi {
+    const SIZE: Vec2 = vec2(16., 16.);
+    fn image(&self) -> Image<'_>;
+}
+impl BuffIconUi for BuffIcon {
+    fn image(&self) -> Image<'_> {
+        use BuffIcon::*;
+        let source = match self {
+            DmgUp => include_image!("../../assets/combat/dmg_up.png"),
+            ResUp => include_image!("../../assets/combat/res_up.png"),
+            ResDown => include_image!("../../assets/combat/res_down.png"),
+
+            Bleed => include_image!("../../assets/combat/bleed.png"),
+            Vulnerable => include_image!("../../assets/combat/vulnerable.png"),
+            Confused => include_image!("../../assets/combat/confused.png"),
+            Echo => include_image!("../../assets/combat/echo.png"),
+
+            Lifelink => include_image!("../../assets/combat/lifelink.png"),
+            Incapacitated => include_image!("../../assets/combat/incapacitated.png"),
+            Dazed => include_image!("../../assets/combat/dazed.png"),
+            Soullink => include_image!("../../assets/combat/soullink.png"),
+
+            // TODO own icons, these borrow the closest existing ones until then
+            Haste => include_image!("../../assets/combat/dmg_up.png"),
+            Slow => include_image!("../../assets/combat/incapacitated.png"),
+        };
+        Image::new(source).fit_to_exact_size(Self::SIZE)
+    }
+}
+
+pub trait EnemyKindUi {
+    fn image(&self) -> Image<'_>;
+    fn idle_animation(&self) -> Animation;
+    fn attack_animation(&self) -> Animation;
+}
+impl EnemyKindUi for EnemyKind {
+    fn image(&self) -> Image<'_> {
+        use EnemyKind::*;
+        match self {
+            Bat       => Spritesheet::Bat.get_sprite_2(0, 0),
+            BigWorn   => Spritesheet::BigWorn.get_sprite_2(0, 0),
+            Minotaur  => Spritesheet::Minotaur.get_sprite_2(0, 0),
+            Orc       => Spritesheet::Orc.get_sprite_2(0, 5),
+            Skeleton  => Spritesheet::Skeleton.get_sprite_2(0, 0),
+            Spider    => Spritesheet::Spider.get_sprite_2(0, 0),
+            BigSpider => Spritesheet::BigSpider.get_sprite_2(0, 1),
+            // TODO boss sprites, they borrow their smaller relatives until then
+            MinotaurKing => Spritesheet::Minotaur.get_sprite_2(0, 0),
+            BroodMother  => Spritesheet::BigSpider.get_sprite_2(0, 1),
+            BoneLord     => Spritesheet::Skeleton.get_sprite_2(0, 0),
+        }
+    }
+    fn idle_animation(&self) -> Animation {
+        use EnemyKind::*;
+        match self {
+            Bat       => Animation::BatIdle,
+            BigWorn   => Animation::BigWormIdle,
+            Minotaur  => Animation::MinotaurIdle,
+            Orc       => Animation::OrcIdle,
+            Skeleton  => Animation::SkeletonIdle,
+            Spider    => Animation::SpiderIdle,
+            BigSpider => Animation::BigSpiderIdle,
+            MinotaurKing => Animation::MinotaurIdle,
+            BroodMother  => Animation::BigSpiderIdle,
+            BoneLord     => Animation::SkeletonIdle,
+        }
+    }
+    fn attack_animation(&self) -> Animation {
+        use EnemyKind::*;
+        match self {
+            Bat       => Animation::BatAttack,
+            BigWorn   => Animation::BigWornAttack,
+            Minotaur  => Animation::MinotaurAttack,
+            Orc       => Animation::OrcAttack,
+            Skeleton  => Animation::SkeletonAttack,
+            Spider    => Animation::SpiderAttack,
+            BigSpider => Animation::BigSpiderAttack,
+            MinotaurKing => Animation::MinotaurAttack,
+            BroodMother  => Animation::BigSpiderAttack,
+            BoneLord     => Animation::SkeletonAttack,
+        }
+    }
+}
+
+pub trait CombatantUi {
+    fn idle_animation(&self) -> Animation;
+    fn attack_animation(&self) -> Animation;
+}
+impl CombatantUi for Combatant {
+    fn idle_animation(&self) -> Animation {
+        match &self.kind {
+            CombatantKind::Fighter => Animation::FighterIdle,
+            CombatantKind::Ranger => Animation::RangerIdle,
+            CombatantKind::Mage => Animation::MageIdle,
+            CombatantKind::Enemy(_, enemy_kind) => enemy_kind.idle_animation(),
+        }
+    }
+    fn attack_animation(&self) -> Animation {
+        match &self.kind {
+            CombatantKind::Fighter => Animation::FighterAttack,
+            CombatantKind::Ranger => Animation::RangerAttack,
+            CombatantKind::Mage => Animation::MageAttack,
+            CombatantKind::Enemy(_, enemy_kind) => enemy_kind.attack_animation(),
+        }
+    }
+}
+
+pub trait SkillUi {
+    fn image(&self) -> Image<'_>;
+}
+impl SkillUi for Skill {
+    fn image(&self) -> Image<'_> {
+        match &self.source {
+            SkillSource::Item { item_type, .. } => item_type.image(),
+            SkillSource::Enemy { .. } => panic!("enemy skills do not have images"),
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use lootforge_core::elemental::Element;
+
+use crate::prelude::*;
+
+pub trait ElementUi {
+    fn color(&self) -> Color32;
+}
+impl ElementUi for Element {
+    fn color(&self) -> Color32 {
+        use Element::*;
+        match self {
+            Bleed => Color32::RED,
+            Fracture => Color32::YELLOW,
+            Madness => Color32::CYAN,
+            Void => Color32::PURPLE,
+        }
+    }
+}
@@ -1,114 +1,32 @@
-use super::{item_type::ItemType, tags::ItemTags};
+use lootforge_core::combat::skill::targeting::Targeting;
+use lootforge_core::item::{item::Item, item_type::ItemType, tags::Rating};
+use lootforge_core::mods::attune;
 
-use crate::item::tags::Rating;
-use crate::mods::attune::Attunement;
 use crate::panels::forge::forge;
 use crate::prelude::*;
-
-use crate::{
-    combat::skill::{targeting::Targeting},
-    mods::*,
-    widgets::text_in_rect::text_in_rect,
-};
-
-
-#[derive(Debug)]
-pub struct Item {
-    pub id: usize,
-    pub item_type: ItemType,
-    // TODO most items don't even have targeting, but I do need something to store inherit stuff, prolly in itemtype data
-    pub targeting: Option<Targeting>,
-    pub mods: Vec<RolledMod>,
-    pub rerolled_mod_idx: u8,
-    pub tags: ItemTags, // TODO we can add a Box here for pointer and then do some mem optimization, because most items have similar tags
-    pub attunements: Vec<Attunement>,
+use crate::widgets::text_in_rect::text_in_rect;
+
+pub trait ItemUi {
+    fn show(&self, ui: &mut Ui) -> Response;
+    fn show_sized(&self, ui: &mut Ui, size: Vec2) -> Response;
+    fn show_tinted(&self, ui: &mut Ui, tint: Color32) -> Response;
+    fn show_tinted_sized(&self, ui: &mut Ui, tint: Color32, size: Vec2) -> Response;
+    fn tooltip(&self, ui: &mut Ui);
 }
-
-pub type ItemRef = Weak<Item>;
-
-impl Item {
-    pub fn new(item_type: ItemType, mods: Vec<RolledMod>, targeting: Option<Targeting>) -> Self {
-        let mut item = Self {
-            id: 0,
-            item_type,
-            targeting,
-            mods,
-            rerolled_mod_idx: u8::MAX,
-            tags: ItemTags::default(),
-            attunements: Vec::new(),
-        };
-        item.recompute_attunements();
-        item
-    }
-    pub fn random(rng: &mut impl Rng, item_type: ItemType, rank: u8) -> Self {
-        Self::random_with_mods(rng, item_type, rank, Vec::new())
-    }
-    pub fn random_with_mods(rng: &mut impl Rng, item_type: ItemType, rank: u8, forced_mods: Vec<RolledMod>) -> Self {
-        let mut mods = Vec::with_capacity(rank as usize);
-
-        mods.extend(forced_mods);
-
-        for _ in mods.len()..rank as usize {
-            mods.push(item_type.roll_mod(rng, &mods));
-        }
-
-        let targeting = if item_type.has_targeting() {
-            Some(Targeting::roll_ring(rng))
-        } else {
-            None
-        };
-
-        Self::new(item_type, mods, targeting)
-    }
-
-    pub fn recompute_attunements(&mut self) {
-        self.attunements = self.mods.iter()
-            .filter_map(|m| m.mod_type().attunement())
-            .map(|(group, idx )| (group.kind, idx))
-            .into_group_map()
-            .iter()
-            .filter(|(_, idx)| idx.iter().all_equal())
-            .map(|(kind, idx)| (*kind, idx[0]))
-            .collect();
-    }
-
-    pub fn rank(&self) -> u8 {
-        self.mods.len().try_into().expect("item has more then 255 mods, WTF")
-    }
-
-    pub fn mod_count(&self, mod_id: u16) -> u8 {
-        self.mods.iter().filter(|m| m.mod_id == mod_id).count() as u8
-    }
-
-    pub fn has_mod(&self, mod_id: u16) -> bool {
-        self.mod_count(mod_id) > 0
-    }
-
-    pub fn has_all_mods<'a>(&self, mut mods: impl Iterator<Item = (u16, u8)>) -> bool {
-        mods.all(|(wanted, count)| self.mod_count(wanted) >= count)
-    }
-
-    pub fn rerolled_mod_idx(&self) ->  Option<u8> {
-        if self.rerolled_mod_idx == u8::MAX {
-            None
-        } else {
-            Some(self.rerolled_mod_idx)
-        }
-    }
-
-    pub fn show(&self, ui: &mut Ui) -> Response {
+impl ItemUi for Item {
+    fn show(&self, ui: &mut Ui) -> Response {
         self.show_tinted_sized(ui, Color32::WHITE, ItemType::SIZE)
     }
 
-    pub fn show_sized(&self, ui: &mut Ui, size: Vec2)  -> Response {
+    fn show_sized(&self, ui: &mut Ui, size: Vec2)  -> Response {
         self.show_tinted_sized(ui, Color32::WHITE, size)
     }
 
-    pub fn show_tinted(&self, ui: &mut Ui, tint: Color32) -> Response {
+    fn show_tinted(&self, ui: &mut Ui, tint: Color32) -> Response {
         self.show_tinted_sized(ui, tint, ItemType::SIZE)
     }
 
-    pub fn show_tinted_sized(&self, ui: &mut Ui, tint: Color32, size: Vec2) -> Response {
+    fn show_tinted_sized(&self, ui: &mut Ui, tint: Color32, size: Vec2) -> Response {
         let response = ui.add(self.item_type.image().tint(tint).fit_to_exact_size(size));
 
         text_in_rect(ui, RichText::new(self.rank().to_string()).color(Color32::WHITE), response.rect, Align2::RIGHT_BOTTOM);
@@ -130,7 +48,7 @@ impl Item {
         response
     }
 
-    pub fn tooltip(&self, ui: &mut Ui) {
+    fn tooltip(&self, ui: &mut Ui) {
         // TODO consider switching the tooltip to using a LayoutJob
         // https://github.com/emilk/egui/blob/master/crates/egui_demo_lib/src/demo/misc_demo_window.rs#L568
 
@@ -189,13 +107,18 @@ impl Item {
                 LowestHealth => ui.label("Target combatant with lowest current health"),
                 LowestResistance(element) => ui.label(format!("Target combatant with lowest resistance to {:?}", element)),
                 HighestMaxHealth => ui.label("Target combatant with highest maximum health"),
-                HighestDamage => ui.label("Target combatant with highest damage"),
+                HighestDamage => ui.label("Target combatant that recently dealt the most damage"),
                 RoundRobin(_) => ui.label("Target a different combatant every attack"),
+                MostDebuffs => ui.label("Target combatant with the most debuffs"),
+                MissingDebuff(debuff) => ui.label(format!("Target combatant without {:?}", debuff)),
+                FocusAlly => ui.label("Target what your allies attacked last"),
+                LowestShield => ui.label("Target combatant with lowest shield"),
                 First => panic!(),
 
                 // Defend
                 Instant => ui.label("Triggers Instantly"),
                 OnAttack => ui.label("Triggers when Attacked"),
+                BelowHealth(percent) => ui.label(format!("Triggers below {}% health", percent)),
             };
             ui.separator();
         }
@@ -211,22 +134,55 @@ impl Item {
     }
 }
 
-impl std::ops::Index<u8> for Item {
-    type Output = RolledMod;
-    fn index<'a>(&'a self, i: u8) -> &'a RolledMod {
-        &self.mods[i as usize]
-    }
-}
-impl PartialEq for Item {
-    fn eq(&self, other: &Self) -> bool {
-        self.id == other.id
-    }
-}
-impl Eq for Item {}
-
 fn tag_in_dungeon() -> RichText {
     RichText::new("D").color(Color32::RED)
 }
 fn tag_in_wardrobe() -> RichText {
     RichText::new("W").color(Color32::YELLOW)
-}
\ No newline at end of file
+}
+
+pub trait ItemTypeUi {
+    const SIZE: Vec2 = vec2(64., 64.);
+    fn image(&self) -> Image<'_>;
+}
+impl ItemTypeUi for ItemType {
+    fn image(&self) -> Image<'_> {
+        use ItemType::*;
+        let source = match *self {
+            Axe => include_image!("../../assets/items/axe.png"),
+            Sword => include_image!("../../assets/items/sword.png"),
+            Shield => include_image!("../../assets/items/shield.png"),
+            Crossbow => include_image!("../../assets/items/hand_crossbow.png"),
+            Bow => include_image!("../../assets/items/bow.png"),
+            Satchel => include_image!("../../assets/items/satchel.png"),
+            Quiver => include_image!("../../assets/items/quiver.png"),
+            AttackGem => include_image!("../../assets/items/gemRed.png"),
+            AoeGem => include_image!("../../assets/items/gemBlue.png"),
+            SupportGem => include_image!("../../assets/items/gemGreen.png"),
+            Staff => include_image!("../../assets/items/staff.png"),
+            Armor => include_image!("../../assets/items/armor.png"),
+            Helmet => include_image!("../../assets/items/helmet.png"),
+            Gloves => include_image!("../../assets/items/gloves.png"),
+            Ring => include_image!("../../assets/items/ring.png"),
+        };
+        Image::new(source).fit_to_exact_size(Self::SIZE)
+    }
+}
+
+pub trait RatingUi {
+    const SIZE: Vec2 = vec2(16., 16.);
+    fn image(&self) -> Option<Image<'_>>;
+}
+impl RatingUi for Rating {
+    fn image(&self) -> Option<Image<'_>> {
+        use Rating::*;
+        let source = match *self {
+            Favorite => include_image!("../../assets/icons/round-star.png"),
+            Like     => include_image!("../../assets/icons/thumb-up.png"),
+            Neutral  => return None,
+            Dislike  => include_image!("../../assets/icons/thumb-down.png"),
+            Trash   => include_image!("../../assets/icons/trash-can.png"),
+        };
+        Some(Image::new(source).fit_to_exact_size(Self::SIZE))
+    }
+}
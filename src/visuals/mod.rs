@@ -0,0 +1,10 @@
+// egui bound parts of the lootforge_core types, split off so the core can be built headless
+pub mod combat;
+pub mod elemental;
+pub mod item;
+pub mod mods;
+
+pub use combat::{BuffIconUi, BuffsUi, CombatantUi, EnemyKindUi, SkillUi};
+pub use elemental::ElementUi;
+pub use item::{ItemTypeUi, ItemUi, RatingUi};
+pub use mods::{ModTypeUi, RolledModUi};
@@ -0,0 +1,41 @@
+use lootforge_core::mods::{ModType, RolledMod, TooltipPart};
+
+use crate::panels::forge::forge;
+use crate::prelude::*;
+
+pub trait RolledModUi {
+    fn show_tooltip(&self, ui: &mut Ui);
+}
+impl RolledModUi for RolledMod {
+    fn show_tooltip(&self, ui: &mut Ui) {
+        // TODO change default color to a slightly brighter grey
+        // TODO check how poe and d3/4 show mods
+        let mod_type = self.mod_type();
+        if mod_type.attune.is_some() {
+            ui.add(forge::Tab::Attune.image().fit_to_exact_size(vec2(16., 16.)));
+        }
+        mod_type.show_tooltip(ui, self.roll);
+    }
+}
+
+pub trait ModTypeUi {
+    fn show_tooltip(&self, ui: &mut Ui, roll: u16);
+}
+impl ModTypeUi for ModType {
+    fn show_tooltip(&self, ui: &mut Ui, roll: u16) {
+        ui.horizontal(|ui| {
+            ui.spacing_mut().item_spacing = vec2(0., 0.);
+            for part in self.tooltip {
+                match part {
+                    TooltipPart::Text(text) => { ui.label(*text); },
+                    TooltipPart::Roll => { ui.label(roll.to_string()); },
+                    TooltipPart::Range => { ui.colored_label(Color32::GRAY, format!("({}-{})", self.roll_range.start(), self.roll_range.end())); },
+                    TooltipPart::Element(element) => {
+                        let name: &'static str = (*element).into();
+                        ui.colored_label(element.color(), name.to_lowercase());
+                    },
+                }
+            }
+        });
+    }
+}